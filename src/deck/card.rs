@@ -19,6 +19,68 @@ pub enum Rank {
     King,
 }
 
+impl Rank {
+    pub fn get_number(&self) -> usize {
+        match self {
+            Rank::Ace => 1,
+            Rank::Two => 2,
+            Rank::Three => 3,
+            Rank::Four => 4,
+            Rank::Five => 5,
+            Rank::Six => 6,
+            Rank::Seven => 7,
+            Rank::Eight => 8,
+            Rank::Nine => 9,
+            Rank::Ten => 10,
+            Rank::Jack => 11,
+            Rank::Queen => 12,
+            Rank::King => 13,
+        }
+    }
+
+    pub fn from_number(number: usize) -> Option<Rank> {
+        match number {
+            1 => Some(Rank::Ace),
+            2 => Some(Rank::Two),
+            3 => Some(Rank::Three),
+            4 => Some(Rank::Four),
+            5 => Some(Rank::Five),
+            6 => Some(Rank::Six),
+            7 => Some(Rank::Seven),
+            8 => Some(Rank::Eight),
+            9 => Some(Rank::Nine),
+            10 => Some(Rank::Ten),
+            11 => Some(Rank::Jack),
+            12 => Some(Rank::Queen),
+            13 => Some(Rank::King),
+            _ => None,
+        }
+    }
+
+    // A, 2 to 10, J, Q and K
+    pub fn get_code(&self) -> String {
+        match self {
+            Rank::Ace => String::from("A"),
+            Rank::Jack => String::from("J"),
+            Rank::Queen => String::from("Q"),
+            Rank::King => String::from("K"),
+            rank => rank.get_number().to_string(),
+        }
+    }
+
+    // also takes lowercase letters and T for ten
+    pub fn from_code(code: &str) -> Option<Rank> {
+        match code.to_ascii_uppercase().as_str() {
+            "A" => Some(Rank::Ace),
+            "T" => Some(Rank::Ten),
+            "J" => Some(Rank::Jack),
+            "Q" => Some(Rank::Queen),
+            "K" => Some(Rank::King),
+            number => number.parse().ok().and_then(Rank::from_number),
+        }
+    }
+}
+
 #[derive(PartialEq, Clone, Copy)]
 pub enum Suit {
     Clubs,
@@ -27,6 +89,41 @@ pub enum Suit {
     Spades,
 }
 
+impl Suit {
+    pub fn get_code(&self) -> char {
+        match self {
+            Suit::Clubs => 'C',
+            Suit::Diamonds => 'D',
+            Suit::Hearts => 'H',
+            Suit::Spades => 'S',
+        }
+    }
+
+    pub fn get_symbol(&self) -> char {
+        match self {
+            Suit::Clubs => '\u{2663}',
+            Suit::Diamonds => '\u{2666}',
+            Suit::Hearts => '\u{2665}',
+            Suit::Spades => '\u{2660}',
+        }
+    }
+
+    pub fn is_red(&self) -> bool {
+        *self == Suit::Diamonds || *self == Suit::Hearts
+    }
+
+    // takes the letter or the full name, in any case
+    pub fn from_code(code: &str) -> Option<Suit> {
+        match code.to_ascii_lowercase().as_str() {
+            "c" | "clubs" => Some(Suit::Clubs),
+            "d" | "diamonds" => Some(Suit::Diamonds),
+            "h" | "hearts" => Some(Suit::Hearts),
+            "s" | "spades" => Some(Suit::Spades),
+            _ => None,
+        }
+    }
+}
+
 impl fmt::Display for Suit {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -38,6 +135,7 @@ impl fmt::Display for Suit {
     }
 }
 
+#[derive(Clone)]
 pub struct Card {
     rank: Rank,
     suit: Suit,
@@ -55,6 +153,45 @@ impl Card {
         }
     }
 
+    // looks up the unicode playing card for the rank and suit
+    pub fn from_rank_and_suit(rank: Rank, suit: Suit) -> Card {
+        let base: u32 = match suit {
+            Suit::Spades => 0x1F0A0,
+            Suit::Hearts => 0x1F0B0,
+            Suit::Diamonds => 0x1F0C0,
+            Suit::Clubs => 0x1F0D0,
+        };
+
+        // the unicode block has a Knight between the Jack and the Queen
+        let number = match rank {
+            Rank::Queen | Rank::King => rank.get_number() + 1,
+            _ => rank.get_number(),
+        };
+
+        let unicode = std::char::from_u32(base + number as u32).unwrap_or(CARD_BACK);
+        Card::new(rank, suit, unicode)
+    }
+
+    // the rank followed by the suit letter, like 10H or QS
+    pub fn get_code(&self) -> String {
+        format!("{}{}", self.rank.get_code(), self.suit.get_code())
+    }
+
+    // the rank and the suit symbol, like 10♥, readable even when the card is face-down
+    pub fn get_name(&self) -> String {
+        format!("{}{}", self.rank.get_code(), self.suit.get_symbol())
+    }
+
+    // reads a card written like 10H, qs or 7d, the card comes back face-down
+    pub fn from_code(code: &str) -> Option<Card> {
+        if code.len() < 2 || !code.is_ascii() {
+            return None;
+        }
+
+        let (rank, suit) = code.split_at(code.len() - 1);
+        Some(Card::from_rank_and_suit(Rank::from_code(rank)?, Suit::from_code(suit)?))
+    }
+
     pub fn open(&mut self) {
         self.open = true;
     }
@@ -63,22 +200,12 @@ impl Card {
         self.open = false;
     }
 
+    pub fn flip(&mut self) {
+        self.open = !self.open;
+    }
+
     pub fn get_numbered_rank(&self) -> usize {
-        match self.rank {
-            Rank::Ace => 1,
-            Rank::Two => 2,
-            Rank::Three => 3,
-            Rank::Four => 4,
-            Rank::Five => 5,
-            Rank::Six => 6,
-            Rank::Seven => 7,
-            Rank::Eight => 8,
-            Rank::Nine => 9,
-            Rank::Ten => 10,
-            Rank::Jack => 11,
-            Rank::Queen => 12,
-            Rank::King => 13,
-        }
+        self.rank.get_number()
     }
 
     pub fn get_rank(&self) -> Rank {
@@ -86,7 +213,7 @@ impl Card {
     }
 
     pub fn get_suit(&self) -> Suit {
-        self.suit.clone()
+        self.suit
     }
 
     pub fn is_open(&self) -> bool {
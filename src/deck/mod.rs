@@ -1,73 +1,71 @@
-use rand::Rng;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
 
 mod card;
 
 pub use card::{Card, Rank, Suit};
 
+// the cards of each suit, from the Ace to the King
+pub const RANK_COUNT: usize = 13;
+
+const SUITS: [Suit; 4] = [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades];
+
 pub struct Deck {
     pub cards: Vec<Card>
 }
 
 impl Deck {
-    pub fn new() -> Deck {
-        let mut cards: Vec<Card> = Vec::with_capacity(53);
-        let mut sorted_cards = vec![
-            Card::new(Rank::Ace, Suit::Clubs, '\u{1F0D1}'),
-            Card::new(Rank::Two, Suit::Clubs, '\u{1F0D2}'),
-            Card::new(Rank::Three, Suit::Clubs, '\u{1F0D3}'),
-            Card::new(Rank::Four, Suit::Clubs, '\u{1F0D4}'),
-            Card::new(Rank::Five, Suit::Clubs, '\u{1F0D5}'),
-            Card::new(Rank::Six, Suit::Clubs, '\u{1F0D6}'),
-            Card::new(Rank::Seven, Suit::Clubs, '\u{1F0D7}'),
-            Card::new(Rank::Eight, Suit::Clubs, '\u{1F0D8}'),
-            Card::new(Rank::Nine, Suit::Clubs, '\u{1F0D9}'),
-            Card::new(Rank::Ten, Suit::Clubs, '\u{1F0DA}'),
-            Card::new(Rank::Jack, Suit::Clubs, '\u{1F0DB}'),
-            Card::new(Rank::Queen, Suit::Clubs, '\u{1F0DD}'),
-            Card::new(Rank::King, Suit::Clubs, '\u{1F0DE}'),
-            Card::new(Rank::Ace, Suit::Diamonds, '\u{1F0C1}'),
-            Card::new(Rank::Two, Suit::Diamonds, '\u{1F0C2}'),
-            Card::new(Rank::Three, Suit::Diamonds, '\u{1F0C3}'),
-            Card::new(Rank::Four, Suit::Diamonds, '\u{1F0C4}'),
-            Card::new(Rank::Five, Suit::Diamonds, '\u{1F0C5}'),
-            Card::new(Rank::Six, Suit::Diamonds, '\u{1F0C6}'),
-            Card::new(Rank::Seven, Suit::Diamonds, '\u{1F0C7}'),
-            Card::new(Rank::Eight, Suit::Diamonds, '\u{1F0C8}'),
-            Card::new(Rank::Nine, Suit::Diamonds, '\u{1F0C9}'),
-            Card::new(Rank::Ten, Suit::Diamonds, '\u{1F0CA}'),
-            Card::new(Rank::Jack, Suit::Diamonds, '\u{1F0CB}'),
-            Card::new(Rank::Queen, Suit::Diamonds, '\u{1F0CD}'),
-            Card::new(Rank::King, Suit::Diamonds, '\u{1F0CE}'),
-            Card::new(Rank::Ace, Suit::Hearts, '\u{1F0B1}'),
-            Card::new(Rank::Two, Suit::Hearts, '\u{1F0B2}'),
-            Card::new(Rank::Three, Suit::Hearts, '\u{1F0B3}'),
-            Card::new(Rank::Four, Suit::Hearts, '\u{1F0B4}'),
-            Card::new(Rank::Five, Suit::Hearts, '\u{1F0B5}'),
-            Card::new(Rank::Six, Suit::Hearts, '\u{1F0B6}'),
-            Card::new(Rank::Seven, Suit::Hearts, '\u{1F0B7}'),
-            Card::new(Rank::Eight, Suit::Hearts, '\u{1F0B8}'),
-            Card::new(Rank::Nine, Suit::Hearts, '\u{1F0B9}'),
-            Card::new(Rank::Ten, Suit::Hearts, '\u{1F0BA}'),
-            Card::new(Rank::Jack, Suit::Hearts, '\u{1F0BB}'),
-            Card::new(Rank::Queen, Suit::Hearts, '\u{1F0BD}'),
-            Card::new(Rank::King, Suit::Hearts, '\u{1F0BE}'),
-            Card::new(Rank::Ace, Suit::Spades, '\u{1F0A1}'),
-            Card::new(Rank::Two, Suit::Spades, '\u{1F0A2}'),
-            Card::new(Rank::Three, Suit::Spades, '\u{1F0A3}'),
-            Card::new(Rank::Four, Suit::Spades, '\u{1F0A4}'),
-            Card::new(Rank::Five, Suit::Spades, '\u{1F0A5}'),
-            Card::new(Rank::Six, Suit::Spades, '\u{1F0A6}'),
-            Card::new(Rank::Seven, Suit::Spades, '\u{1F0A7}'),
-            Card::new(Rank::Eight, Suit::Spades, '\u{1F0A8}'),
-            Card::new(Rank::Nine, Suit::Spades, '\u{1F0A9}'),
-            Card::new(Rank::Ten, Suit::Spades, '\u{1F0AA}'),
-            Card::new(Rank::Jack, Suit::Spades, '\u{1F0AB}'),
-            Card::new(Rank::Queen, Suit::Spades, '\u{1F0AD}'),
-            Card::new(Rank::King, Suit::Spades, '\u{1F0AE}'),
-        ];
-
-        for _ in 0..52 {
-            let i = rand::thread_rng().gen_range(0, sorted_cards.len());
+    // the same seed always gives the same order of cards
+    pub fn from_seed(seed: u64) -> Deck {
+        Deck::from_seed_with_suits(seed, &SUITS, 1)
+    }
+
+    // the deals of the FreeCell that came with Windows, numbered from 1 to 32000,
+    // so that dealing the cards one by one gives the well-known layouts
+    pub fn from_microsoft_deal(deal_number: u64) -> Deck {
+        // the C library rand() of the time
+        let mut seed = deal_number as u32;
+        let mut rand = || {
+            seed = seed.wrapping_mul(214_013).wrapping_add(2_531_011) & 0x7FFF_FFFF;
+            (seed >> 16) as usize
+        };
+
+        // cards are numbered rank first, the suits going clubs, diamonds, hearts and spades
+        let mut numbers: Vec<usize> = (0..52).rev().collect();
+        for i in 0..51 {
+            let j = 51 - rand() % (52 - i);
+            numbers.swap(i, j);
+        }
+
+        // dealing takes cards off the end
+        let cards = numbers.iter()
+            .rev()
+            .filter_map(|number| Some(Card::from_rank_and_suit(Rank::from_number(number / 4 + 1)?, SUITS[number % 4])))
+            .collect();
+
+        Deck {
+            cards
+        }
+    }
+
+    // `copies` of every card of the given suits, shuffled the same way for the same seed
+    pub fn from_seed_with_suits(seed: u64, suits: &[Suit], copies: usize) -> Deck {
+        // unlike StdRng, ChaCha8Rng promises the same numbers on every platform and version
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let mut sorted_cards: Vec<Card> = Vec::with_capacity(RANK_COUNT * suits.len() * copies);
+        for _ in 0..copies {
+            for suit in suits.iter() {
+                for number in 1..=RANK_COUNT {
+                    if let Some(rank) = Rank::from_number(number) {
+                        sorted_cards.push(Card::from_rank_and_suit(rank, *suit));
+                    }
+                }
+            }
+        }
+
+        let mut cards = Vec::with_capacity(sorted_cards.len());
+        while !sorted_cards.is_empty() {
+            let i = rng.gen_range(0, sorted_cards.len());
             cards.push(sorted_cards.remove(i));
         }
 
@@ -85,4 +83,52 @@ impl Deck {
         }
         None
     }
+
+    // deals the rest of the cards face up a row at a time across `count` columns,
+    // so the columns on the left get one more when the cards do not go round evenly
+    pub fn deal_rows(&mut self, count: usize) -> Vec<Vec<Card>> {
+        let mut columns: Vec<Vec<Card>> = (0..count).map(|_| Vec::new()).collect();
+        let mut i = 0;
+        while let Some(card) = self.deal(true) {
+            columns[i % count].push(card);
+            i += 1;
+        }
+
+        columns
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the cards in the order they are dealt
+    fn dealt_codes(mut deck: Deck) -> Vec<String> {
+        let mut codes = Vec::new();
+        while let Some(card) = deck.deal(true) {
+            codes.push(card.get_code());
+        }
+        codes
+    }
+
+    #[test]
+    fn microsoft_deal_one_has_the_known_layout() {
+        let codes = dealt_codes(Deck::from_microsoft_deal(1));
+
+        assert_eq!(codes.len(), 52);
+        assert_eq!(codes[..16], ["JD", "2D", "9H", "JC", "5D", "7H", "7C", "5H", "KD", "KC", "9S", "5S", "AD", "QC", "KH", "3H"]);
+        assert_eq!(codes[48..], ["6S", "9C", "2H", "6H"]);
+    }
+
+    // deal numbers are shared between players, so they must not change with the rand version
+    #[test]
+    fn a_deal_number_always_gives_the_same_cards() {
+        assert_eq!(dealt_codes(Deck::from_seed(1))[..8], ["5S", "4S", "2C", "JD", "3C", "QS", "AH", "4C"]);
+    }
+
+    #[test]
+    fn the_same_seed_gives_the_same_deal() {
+        assert_eq!(dealt_codes(Deck::from_seed(42)), dealt_codes(Deck::from_seed(42)));
+        assert_ne!(dealt_codes(Deck::from_seed(42)), dealt_codes(Deck::from_seed(43)));
+    }
 }
@@ -0,0 +1,111 @@
+use std::fmt;
+use crate::deck::{Card, Deck};
+use crate::game::board::{Board, Op};
+use crate::game::column::Column;
+use crate::game::render;
+use crate::game::variant::{self, Variant};
+use crate::game::{MoveError, PileId, PileKind, Spot};
+
+// piles shown on each line of the row
+const ROW_LENGTH: usize = 13;
+// how far left, counting the piles still there, a pile may go
+const STEPS: [usize; 2] = [1, 3];
+
+// the whole deck dealt in a row, gathered into a single pile by putting piles on the one next to
+// them or three to their left when their top cards match in suit or rank
+#[derive(Clone)]
+pub struct Accordion;
+
+impl Accordion {
+    pub fn new() -> Accordion {
+        Accordion
+    }
+}
+
+impl Variant for Accordion {
+    fn get_name(&self) -> &'static str {
+        "accordion"
+    }
+
+    fn clone_box(&self) -> Box<dyn Variant> {
+        Box::new(self.clone())
+    }
+
+    fn get_title(&self) -> String {
+        String::from("Accordion")
+    }
+
+    fn deal(&self, deal_number: u64) -> Board {
+        let mut deck = Deck::from_seed(deal_number);
+
+        let mut board = Board::new();
+        while let Some(card) = deck.deal(true) {
+            board.add(Box::new(Column::new(vec![card])));
+        }
+
+        board
+    }
+
+    // the piles left, closing up the gaps, each with the number it was dealt at
+    fn render(&self, board: &Board, hinted: &dyn Fn(Spot) -> bool, f: &mut fmt::Formatter) -> fmt::Result {
+        let piles: Vec<PileId> = board.ids_of(PileKind::Tableau)
+            .into_iter()
+            .filter(|id| board.get_len(*id) > 0)
+            .collect();
+
+        for row in piles.chunks(ROW_LENGTH) {
+            let numbers: String = row.iter().map(|id| format!("{:>2} ", id.number)).collect();
+            writeln!(f, "{}", numbers.trim_end())?;
+            for id in row {
+                render::write_top(f, board, *id, ' ', hinted)?;
+            }
+            write!(f, "\n\n")?;
+        }
+
+        Ok(())
+    }
+
+    // top cards matching in suit or rank
+    fn check_put(&self, board: &Board, to: PileId, card: &Card, _size: usize) -> Result<(), MoveError> {
+        let onto = board.last(to).ok_or(MoveError::CannotMoveTo(to))?;
+
+        if card.get_suit() == onto.get_suit() || card.get_rank() == onto.get_rank() {
+            Ok(())
+        } else {
+            Err(MoveError::NoMatch { card: card.clone(), onto: onto.clone() })
+        }
+    }
+
+    fn check_move(&self, board: &Board, from: PileId, to: PileId, size: usize) -> Result<(), MoveError> {
+        variant::check_pile_move(self, board, from, to, size)?;
+
+        let left: Vec<PileId> = board.ids_of(PileKind::Tableau)
+            .into_iter()
+            .filter(|id| id.number < from.number && board.get_len(*id) > 0)
+            .rev()
+            .collect();
+        if STEPS.iter().any(|step| left.get(step - 1) == Some(&to)) {
+            Ok(())
+        } else {
+            Err(MoveError::NotOneOrThreeLeft { card: board.last(from).cloned().ok_or(MoveError::EmptyPile(from))? })
+        }
+    }
+
+    // a pile moves as a whole, by its top card
+    fn move_ops(&self, board: &Board, from: PileId, to: PileId, _size: usize) -> Vec<Op> {
+        vec![Op::Move { from, to, count: board.get_len(from) }]
+    }
+
+    // everything gathered into one pile
+    fn is_won(&self, board: &Board) -> bool {
+        board.ids_of(PileKind::Tableau).into_iter().filter(|id| board.get_len(*id) > 0).count() == 1
+    }
+
+    fn is_safe_on_foundation(&self, _board: &Board, _card: &Card) -> bool {
+        false
+    }
+
+    fn has_ordered_columns(&self) -> bool {
+        true
+    }
+}
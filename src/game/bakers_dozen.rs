@@ -0,0 +1,71 @@
+use std::fmt;
+use crate::deck::{Card, Deck, Rank};
+use crate::game::board::Board;
+use crate::game::foundation::Foundation;
+use crate::game::rank_tableau::RankTableau;
+use crate::game::render;
+use crate::game::variant::{self, Variant};
+use crate::game::{PileKind, Spot, SUITS};
+
+const TABLEAU_COUNT: usize = 13;
+const COLUMN_SIZE: usize = 4;
+
+// thirteen open columns of four with the Kings put at the bottom, built down regardless of suit
+// and never refilled once empty
+#[derive(Clone)]
+pub struct BakersDozen;
+
+impl BakersDozen {
+    pub fn new() -> BakersDozen {
+        BakersDozen
+    }
+}
+
+impl Variant for BakersDozen {
+    fn get_name(&self) -> &'static str {
+        "bakersdozen"
+    }
+
+    fn clone_box(&self) -> Box<dyn Variant> {
+        Box::new(self.clone())
+    }
+
+    fn get_title(&self) -> String {
+        String::from("Baker's Dozen")
+    }
+
+    fn deal(&self, deal_number: u64) -> Board {
+        let mut deck = Deck::from_seed(deal_number);
+        let columns: Vec<Vec<Card>> = (0..TABLEAU_COUNT)
+            .map(|_| {
+                let mut column: Vec<Card> = (0..COLUMN_SIZE).map(|_| deck.deal(true).unwrap()).collect();
+                // the Kings go under the other cards, keeping their order
+                column.sort_by_key(|card| card.get_rank() != Rank::King);
+                column
+            })
+            .collect();
+
+        let mut board = Board::new();
+        for suit in SUITS.iter() {
+            board.add(Box::new(Foundation::new(*suit, false)));
+        }
+        for column in columns {
+            board.add(Box::new(RankTableau::new(column, false)));
+        }
+
+        board
+    }
+
+    fn render(&self, board: &Board, hinted: &dyn Fn(Spot) -> bool, f: &mut fmt::Formatter) -> fmt::Result {
+        for (id, suit) in board.ids_of(PileKind::Foundation).iter().zip(SUITS.iter()) {
+            render::write_top(f, board, *id, suit.get_symbol(), hinted)?;
+        }
+        write!(f, "\n\n")?;
+
+        render::write_columns(f, board, &board.ids_of(PileKind::Tableau), hinted)
+    }
+
+    fn is_safe_on_foundation(&self, board: &Board, card: &Card) -> bool {
+        variant::is_safe_in_any_suit(board, card)
+    }
+}
@@ -0,0 +1,99 @@
+use std::fmt;
+use crate::deck::{Card, Deck, Rank};
+use crate::game::board::Board;
+use crate::game::foundation::Foundation;
+use crate::game::rank_tableau::RankTableau;
+use crate::game::render;
+use crate::game::variant::{self, Variant};
+use crate::game::{Pile, PileKind, Spot, SUITS};
+
+const TABLEAU_COUNT: usize = 8;
+
+// the Aces start the foundations and the rest is dealt open onto eight columns,
+// built down regardless of suit
+#[derive(Clone)]
+pub struct BeleagueredCastle;
+
+impl BeleagueredCastle {
+    pub fn new() -> BeleagueredCastle {
+        BeleagueredCastle
+    }
+}
+
+impl Variant for BeleagueredCastle {
+    fn get_name(&self) -> &'static str {
+        "beleagueredcastle"
+    }
+
+    fn clone_box(&self) -> Box<dyn Variant> {
+        Box::new(self.clone())
+    }
+
+    fn get_title(&self) -> String {
+        String::from("Beleaguered Castle")
+    }
+
+    fn deal(&self, deal_number: u64) -> Board {
+        let mut deck = Deck::from_seed(deal_number);
+        let mut foundations: Vec<Foundation> = SUITS.iter().map(|suit| Foundation::new(*suit, false)).collect();
+        let mut columns: Vec<Vec<Card>> = (0..TABLEAU_COUNT).map(|_| Vec::new()).collect();
+
+        let mut i = 0;
+        while let Some(card) = deck.deal(true) {
+            if card.get_rank() == Rank::Ace {
+                if let Some(foundation) = foundations.iter_mut().find(|foundation| foundation.can_push(&card)) {
+                    foundation.place(card);
+                }
+            } else {
+                columns[i % TABLEAU_COUNT].push(card);
+                i += 1;
+            }
+        }
+
+        let mut board = Board::new();
+        for foundation in foundations {
+            board.add(Box::new(foundation));
+        }
+        for column in columns {
+            board.add(Box::new(RankTableau::new(column, true)));
+        }
+
+        board
+    }
+
+    fn render(&self, board: &Board, hinted: &dyn Fn(Spot) -> bool, f: &mut fmt::Formatter) -> fmt::Result {
+        for (id, suit) in board.ids_of(PileKind::Foundation).iter().zip(SUITS.iter()) {
+            render::write_top(f, board, *id, suit.get_symbol(), hinted)?;
+        }
+        write!(f, "\n\n")?;
+
+        render::write_columns(f, board, &board.ids_of(PileKind::Tableau), hinted)
+    }
+
+    fn is_safe_on_foundation(&self, board: &Board, card: &Card) -> bool {
+        variant::is_safe_in_any_suit(board, card)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_aces_start_on_the_foundations_and_the_rest_fill_the_columns() {
+        for deal_number in 1..20 {
+            let board = BeleagueredCastle::new().deal(deal_number);
+
+            for (id, suit) in board.ids_of(PileKind::Foundation).iter().zip(SUITS.iter()) {
+                let cards = board.get_cards(*id);
+                assert_eq!(cards.len(), 1);
+                assert!(cards[0].get_rank() == Rank::Ace && cards[0].get_suit() == *suit);
+            }
+            for id in board.ids_of(PileKind::Tableau) {
+                let cards = board.get_cards(id);
+                assert_eq!(cards.len(), 6);
+                assert!(cards.iter().all(|card| card.is_open() && card.get_rank() != Rank::Ace));
+            }
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use crate::deck::{Card, Rank, Suit, RANK_COUNT};
+use crate::game::pile::{Pile, PileId, PileKind};
+
+// one step of a move, small enough to be taken back exactly
+#[derive(Clone)]
+pub enum Op {
+    // `count` cards go over in the same order
+    Move { from: PileId, to: PileId, count: usize },
+    // `count` cards are taken one at a time and turned over, like dealing from the stock
+    Turn { from: PileId, to: PileId, count: usize },
+    // turns the top card of a pile over
+    Flip(PileId),
+    // counts one more pass through the stock
+    Recycle,
+}
+
+// the piles of a game laid out on the table
+#[derive(Clone)]
+pub struct Board {
+    ids: Vec<PileId>,
+    piles: Vec<Box<dyn Pile>>,
+    // times the waste was turned back into the stock
+    recycles: usize,
+}
+
+fn card_key(card: &Card) -> u8 {
+    let suit = match card.get_suit() {
+        Suit::Clubs => 0,
+        Suit::Diamonds => 1,
+        Suit::Hearts => 2,
+        Suit::Spades => 3,
+    };
+    let open = if card.is_open() { 64 } else { 0 };
+    suit * 13 + card.get_numbered_rank() as u8 + open
+}
+
+impl Board {
+    pub fn new() -> Board {
+        Board {
+            ids: Vec::new(),
+            piles: Vec::new(),
+            recycles: 0,
+        }
+    }
+
+    // piles are numbered in the order they are added
+    pub fn add(&mut self, pile: Box<dyn Pile>) -> PileId {
+        let kind = pile.get_kind();
+        let id = PileId::new(kind, self.ids.iter().filter(|id| id.kind == kind).count() + 1);
+        self.ids.push(id);
+        self.piles.push(pile);
+        id
+    }
+
+    pub fn get_ids(&self) -> &[PileId] {
+        &self.ids
+    }
+
+    pub fn ids_of(&self, kind: PileKind) -> Vec<PileId> {
+        self.ids.iter().copied().filter(|id| id.kind == kind).collect()
+    }
+
+    // the first pile of a kind, for the kinds there is only one of
+    pub fn first_of(&self, kind: PileKind) -> Option<PileId> {
+        self.ids.iter().copied().find(|id| id.kind == kind)
+    }
+
+    pub fn get_pile(&self, id: PileId) -> Option<&dyn Pile> {
+        let i = self.ids.iter().position(|pile_id| *pile_id == id)?;
+        Some(self.piles[i].as_ref())
+    }
+
+    pub fn get_pile_mut(&mut self, id: PileId) -> Option<&mut Box<dyn Pile>> {
+        let i = self.ids.iter().position(|pile_id| *pile_id == id)?;
+        Some(&mut self.piles[i])
+    }
+
+    // missing piles count as empty
+    pub fn get_len(&self, id: PileId) -> usize {
+        self.get_pile(id).map_or(0, |pile| pile.len())
+    }
+
+    pub fn get_card(&self, id: PileId, i: usize) -> Option<&Card> {
+        self.get_pile(id)?.get(i)
+    }
+
+    pub fn last(&self, id: PileId) -> Option<&Card> {
+        self.get_pile(id)?.last()
+    }
+
+    pub fn get_cards(&self, id: PileId) -> &[Card] {
+        self.get_pile(id).map_or(&[], |pile| pile.get_cards().as_slice())
+    }
+
+    // how many cards the piles of a kind hold between them
+    pub fn count(&self, kind: PileKind) -> usize {
+        self.ids.iter().zip(self.piles.iter())
+            .filter(|(id, _)| id.kind == kind)
+            .map(|(_, pile)| pile.len())
+            .sum()
+    }
+
+    pub fn count_all(&self) -> usize {
+        self.piles.iter().map(|pile| pile.len()).sum()
+    }
+
+    pub fn get_recycles(&self) -> usize {
+        self.recycles
+    }
+
+    pub fn set_recycles(&mut self, recycles: usize) {
+        self.recycles = recycles;
+    }
+
+    // takes every card off the board, leaving the empty piles
+    pub fn clear(&mut self) -> Vec<Card> {
+        self.piles.iter_mut().flat_map(|pile| pile.get_cards_mut().drain(..).collect::<Vec<Card>>()).collect()
+    }
+
+    // the foundation building a suit, or else the first one a card of that suit could start
+    pub fn foundation_of_suit(&self, suit: Suit) -> Option<PileId> {
+        let foundations = self.ids_of(PileKind::Foundation);
+        // the foundations do not all start with the Ace
+        let starters: Vec<Card> = (1..=RANK_COUNT)
+            .filter_map(Rank::from_number)
+            .map(|rank| {
+                let mut card = Card::from_rank_and_suit(rank, suit);
+                card.open();
+                card
+            })
+            .collect();
+
+        foundations.iter().copied()
+            .find(|id| self.get_card(*id, 0).is_some_and(|card| card.get_suit() == suit))
+            .or_else(|| foundations.iter().copied().find(|id| self.get_pile(*id).is_some_and(|pile| pile.is_empty() && starters.iter().any(|card| pile.can_push(card)))))
+    }
+
+    fn take(&mut self, id: PileId, count: usize) -> Vec<Card> {
+        match self.get_pile_mut(id) {
+            Some(pile) => {
+                let cards = pile.get_cards_mut();
+                let at = cards.len().saturating_sub(count);
+                cards.split_off(at)
+            },
+            None => Vec::new(),
+        }
+    }
+
+    fn put(&mut self, id: PileId, cards: Vec<Card>) {
+        if let Some(pile) = self.get_pile_mut(id) {
+            for card in cards {
+                pile.place(card);
+            }
+        }
+    }
+
+    fn turn(&mut self, from: PileId, to: PileId, count: usize) {
+        let mut cards = self.take(from, count);
+        cards.reverse();
+        for card in cards.iter_mut() {
+            card.flip();
+        }
+        self.put(to, cards);
+    }
+
+    fn flip(&mut self, id: PileId) {
+        if let Some(card) = self.get_pile_mut(id).and_then(|pile| pile.get_cards_mut().last_mut()) {
+            card.flip();
+        }
+    }
+
+    pub fn apply(&mut self, op: &Op) {
+        match *op {
+            Op::Move { from, to, count } => {
+                let cards = self.take(from, count);
+                self.put(to, cards);
+            },
+            Op::Turn { from, to, count } => self.turn(from, to, count),
+            Op::Flip(id) => self.flip(id),
+            Op::Recycle => self.recycles += 1,
+        }
+    }
+
+    pub fn revert(&mut self, op: &Op) {
+        match *op {
+            Op::Move { from, to, count } => {
+                let cards = self.take(to, count);
+                self.put(from, cards);
+            },
+            Op::Turn { from, to, count } => self.turn(to, from, count),
+            Op::Flip(id) => self.flip(id),
+            Op::Recycle => self.recycles -= 1,
+        }
+    }
+
+    // identifies the position regardless of the order of the cells, and of the tableau columns
+    // unless `ordered_columns` says where a column is matters
+    pub fn position_key(&self, ordered_columns: bool) -> Vec<u8> {
+        let mut groups: Vec<(PileKind, Vec<u8>)> = self.ids.iter().zip(self.piles.iter())
+            .map(|(id, pile)| (id.kind, pile.get_cards().iter().map(card_key).collect()))
+            .collect();
+
+        // piles of these kinds are interchangeable
+        groups.sort_by(|a, b| {
+            let interchangeable = a.0 == PileKind::Cell || (a.0 == PileKind::Tableau && !ordered_columns);
+            a.0.cmp(&b.0).then(if interchangeable { a.1.cmp(&b.1) } else { std::cmp::Ordering::Equal })
+        });
+
+        let mut key = Vec::new();
+        for (_, pile) in groups {
+            key.extend(pile);
+            key.push(u8::MAX);
+        }
+
+        key
+    }
+}
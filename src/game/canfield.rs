@@ -0,0 +1,140 @@
+use std::fmt;
+use crate::deck::{Card, Deck};
+use crate::game::board::{Board, Op};
+use crate::game::canfield_tableau::CanfieldTableau;
+use crate::game::foundation::Foundation;
+use crate::game::render;
+use crate::game::reserve::Reserve;
+use crate::game::stock::Stock;
+use crate::game::variant::{self, Variant};
+use crate::game::waste::Waste;
+use crate::game::{MoveError, Pile, PileId, PileKind, Spot, SUITS};
+
+const TABLEAU_COUNT: usize = 4;
+const RESERVE_SIZE: usize = 13;
+const DRAW_COUNT: usize = 3;
+
+const STOCK: PileId = PileId { kind: PileKind::Stock, number: 1 };
+const WASTE: PileId = PileId { kind: PileKind::Waste, number: 1 };
+const RESERVE: PileId = PileId { kind: PileKind::Reserve, number: 1 };
+
+// a reserve of 13 cards feeding four columns, the foundations starting from the rank of the
+// first card dealt to them and going round from King to Ace
+#[derive(Clone)]
+pub struct Canfield;
+
+impl Canfield {
+    pub fn new() -> Canfield {
+        Canfield
+    }
+}
+
+impl Variant for Canfield {
+    fn get_name(&self) -> &'static str {
+        "canfield"
+    }
+
+    fn clone_box(&self) -> Box<dyn Variant> {
+        Box::new(self.clone())
+    }
+
+    fn get_title(&self) -> String {
+        String::from("Canfield")
+    }
+
+    fn deal(&self, deal_number: u64) -> Board {
+        let mut deck = Deck::from_seed(deal_number);
+
+        // only the top card of the reserve is face-up
+        let reserve: Vec<Card> = (1..=RESERVE_SIZE).map(|i| deck.deal(i == RESERVE_SIZE).unwrap()).collect();
+        let base = deck.deal(true).unwrap();
+        let columns: Vec<Vec<Card>> = (0..TABLEAU_COUNT).map(|_| vec![deck.deal(true).unwrap()]).collect();
+
+        let mut board = Board::new();
+        board.add(Box::new(Stock::new(deck.cards)));
+        board.add(Box::new(Waste::new()));
+        board.add(Box::new(Reserve::new(reserve)));
+        for suit in SUITS.iter() {
+            let mut foundation = Foundation::with_base(*suit, base.get_rank());
+            if base.get_suit() == *suit {
+                foundation.place(base.clone());
+            }
+            board.add(Box::new(foundation));
+        }
+        for column in columns {
+            board.add(Box::new(CanfieldTableau::new(column)));
+        }
+
+        board
+    }
+
+    fn render(&self, board: &Board, hinted: &dyn Fn(Spot) -> bool, f: &mut fmt::Formatter) -> fmt::Result {
+        for (id, suit) in board.ids_of(PileKind::Foundation).iter().zip(SUITS.iter()) {
+            render::write_top(f, board, *id, suit.get_symbol(), hinted)?;
+        }
+        render::write_waste(f, board, STOCK, WASTE, DRAW_COUNT, hinted)?;
+        write!(f, "\n\n")?;
+
+        render::write_top(f, board, RESERVE, '\u{00B7}', hinted)?;
+        write!(f, " {} card(s) in reserve\n\n", board.get_len(RESERVE))?;
+
+        render::write_columns(f, board, &board.ids_of(PileKind::Tableau), hinted)
+    }
+
+    fn deal_ops(&self, board: &Board) -> Result<Vec<Op>, MoveError> {
+        variant::deal_to_waste(self, board, STOCK, WASTE)
+    }
+
+    // the reserve fills the empty columns by itself, the player fills them once it runs out
+    fn after_move(&self, board: &Board) -> Vec<Op> {
+        if board.get_len(RESERVE) > 0 {
+            if let Some(to) = board.ids_of(PileKind::Tableau).into_iter().find(|id| board.get_len(*id) == 0) {
+                return vec![Op::Move { from: RESERVE, to, count: 1 }];
+            }
+        }
+
+        variant::flip_tops(board)
+    }
+
+    fn is_safe_on_foundation(&self, _board: &Board, _card: &Card) -> bool {
+        false
+    }
+
+    fn get_draw_count(&self) -> usize {
+        DRAW_COUNT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::tests::{position, rest_of_deck};
+    use crate::game::Move;
+
+    fn column(number: usize) -> PileId {
+        PileId::new(PileKind::Tableau, number)
+    }
+
+    #[test]
+    fn the_reserve_fills_an_emptied_column() {
+        let mut piles = vec![
+            "reserve ~2D 9C".to_string(),
+            "tableau 1 5H".to_string(),
+            "tableau 2 6S".to_string(),
+            "tableau 3 JD".to_string(),
+            "tableau 4 3C".to_string(),
+        ];
+        piles.push(format!("stock {}", rest_of_deck(Box::new(Canfield::new()), &piles)));
+        let mut game = position(Box::new(Canfield::new()), &piles);
+
+        assert!(game.play(Move::Transfer(column(1), column(2), 1)).is_ok());
+        assert_eq!(game.board.get_cards(column(1))[0].get_code(), "9C");
+        assert_eq!(game.get_pile_len(RESERVE), 1);
+        assert!(game.board.get_cards(RESERVE)[0].is_open());
+
+        assert!(game.undo().is_some());
+        assert_eq!(game.board.get_cards(column(1))[0].get_code(), "5H");
+        assert_eq!(game.get_pile_len(RESERVE), 2);
+        assert!(!game.board.get_cards(RESERVE)[0].is_open());
+    }
+}
@@ -0,0 +1,67 @@
+use crate::game::{MoveError, Pile, PileKind};
+use crate::deck::{Card, RANK_COUNT};
+
+// one rank lower and the other color, with a King going on an Ace
+fn check_wrapping_stack(card: &Card, onto: &Card) -> Result<(), MoveError> {
+    if onto.get_numbered_rank() != card.get_numbered_rank() % RANK_COUNT + 1 {
+        return Err(MoveError::WrongRank { card: card.clone(), onto: onto.clone() });
+    }
+    if onto.get_suit().is_red() == card.get_suit().is_red() {
+        return Err(MoveError::SameColor { card: card.clone(), onto: onto.clone() });
+    }
+
+    Ok(())
+}
+
+// builds down in alternate colors going round from Ace to King, any card fills a space
+#[derive(Clone)]
+pub struct CanfieldTableau {
+    stack: Vec<Card>,
+}
+
+impl CanfieldTableau {
+    pub fn new(stack: Vec<Card>) -> CanfieldTableau {
+        CanfieldTableau {
+            stack,
+        }
+    }
+}
+
+impl Pile for CanfieldTableau {
+    fn get_kind(&self) -> PileKind {
+        PileKind::Tableau
+    }
+
+    fn get_cards(&self) -> &Vec<Card> {
+        &self.stack
+    }
+
+    fn get_cards_mut(&mut self) -> &mut Vec<Card> {
+        &mut self.stack
+    }
+
+    fn clone_box(&self) -> Box<dyn Pile> {
+        Box::new(self.clone())
+    }
+
+    fn check_push(&self, card: &Card) -> Result<(), MoveError> {
+        match self.stack.last() {
+            Some(last) => check_wrapping_stack(card, last),
+            None => Ok(()),
+        }
+    }
+
+    fn moves_runs(&self) -> bool {
+        true
+    }
+
+    fn check_run(&self, run: &[Card]) -> Result<(), MoveError> {
+        for pair in run.windows(2) {
+            if check_wrapping_stack(&pair[1], &pair[0]).is_err() {
+                return Err(MoveError::BrokenRun { card: pair[1].clone(), onto: pair[0].clone() });
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use crate::game::{MoveError, Pile, PileKind};
+use crate::deck::Card;
+
+// a free cell, parking a single card of any kind
+#[derive(Clone)]
+pub struct Cell {
+    stack: Vec<Card>,
+}
+
+impl Cell {
+    pub fn new() -> Cell {
+        Cell {
+            stack: Vec::with_capacity(1),
+        }
+    }
+}
+
+impl Pile for Cell {
+    fn get_kind(&self) -> PileKind {
+        PileKind::Cell
+    }
+
+    fn get_cards(&self) -> &Vec<Card> {
+        &self.stack
+    }
+
+    fn get_cards_mut(&mut self) -> &mut Vec<Card> {
+        &mut self.stack
+    }
+
+    fn clone_box(&self) -> Box<dyn Pile> {
+        Box::new(self.clone())
+    }
+
+    fn check_push(&self, _card: &Card) -> Result<(), MoveError> {
+        if self.stack.is_empty() {
+            Ok(())
+        } else {
+            Err(MoveError::CellTaken)
+        }
+    }
+}
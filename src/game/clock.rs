@@ -0,0 +1,149 @@
+use std::fmt;
+use crate::deck::{Card, Deck, RANK_COUNT};
+use crate::game::board::{Board, Op};
+use crate::game::cell::Cell;
+use crate::game::column::Column;
+use crate::game::discard::Discard;
+use crate::game::render;
+use crate::game::variant::Variant;
+use crate::game::{MoveError, PileId, PileKind, Spot};
+
+const PILE_SIZE: usize = 4;
+// one pile for each rank, the hours of the clock with the Kings in the middle,
+// drawn on the clock face at the line and the character given here
+const FACE: [(usize, usize); RANK_COUNT] = [
+    (1, 18), (2, 22), (3, 24), (4, 22), (5, 18), (6, 12),
+    (5, 6), (4, 2), (3, 0), (2, 2), (1, 6), (0, 12), (3, 12),
+];
+
+// the card turned up last, waiting to go under the pile of its rank
+const HAND: PileId = PileId { kind: PileKind::Cell, number: 1 };
+const KINGS: PileId = PileId { kind: PileKind::Tableau, number: RANK_COUNT };
+
+// thirteen face-down piles, each card turned up going under the pile of its rank and bringing up
+// the next card from there, won when every card is turned before the fourth King
+#[derive(Clone)]
+pub struct Clock;
+
+impl Clock {
+    pub fn new() -> Clock {
+        Clock
+    }
+}
+
+impl Variant for Clock {
+    fn get_name(&self) -> &'static str {
+        "clock"
+    }
+
+    fn clone_box(&self) -> Box<dyn Variant> {
+        Box::new(self.clone())
+    }
+
+    fn get_title(&self) -> String {
+        String::from("Clock")
+    }
+
+    fn deal(&self, deal_number: u64) -> Board {
+        let mut deck = Deck::from_seed(deal_number);
+        let mut piles: Vec<Vec<Card>> = (0..RANK_COUNT).map(|_| Vec::with_capacity(PILE_SIZE)).collect();
+        for i in 0..RANK_COUNT * PILE_SIZE {
+            piles[i % RANK_COUNT].push(deck.deal(false).unwrap());
+        }
+
+        // the piles and the places the cards turned up go to, both numbered by rank
+        let mut board = Board::new();
+        board.add(Box::new(Cell::new()));
+        for _ in 0..RANK_COUNT {
+            board.add(Box::new(Discard::new()));
+        }
+        for pile in piles {
+            board.add(Box::new(Column::new(pile)));
+        }
+
+        board
+    }
+
+    // each hour shows the last card put under it, or its face-down pile
+    fn render(&self, board: &Board, hinted: &dyn Fn(Spot) -> bool, f: &mut fmt::Formatter) -> fmt::Result {
+        let lines = FACE.iter().map(|(line, _)| line + 1).max().unwrap_or(0);
+
+        for line in 0..lines {
+            let mut hours: Vec<(usize, usize)> = FACE.iter()
+                .enumerate()
+                .filter(|(_, (at, _))| *at == line)
+                .map(|(i, (_, column))| (*column, i + 1))
+                .collect();
+            hours.sort_unstable();
+
+            let mut width = 0;
+            for (column, rank) in hours {
+                let foundation = PileId::new(PileKind::Foundation, rank);
+                let id = if board.get_len(foundation) > 0 { foundation } else { PileId::new(PileKind::Tableau, rank) };
+                write!(f, "{:1$}", "", column - width)?;
+                render::write_top(f, board, id, '\u{00B7}', hinted)?;
+                width = column + 3;
+            }
+            writeln!(f)?;
+        }
+
+        write!(f, "\nTurned up:")?;
+        render::write_top(f, board, HAND, ' ', hinted)?;
+        write!(f, "\n\n")
+    }
+
+    // the card in hand goes under its hour and the next one comes up from there,
+    // the first one from the Kings
+    fn deal_ops(&self, board: &Board) -> Result<Vec<Op>, MoveError> {
+        let card = match board.last(HAND) {
+            Some(card) => card,
+            None if board.count(PileKind::Foundation) == 0 => return Ok(vec![Op::Turn { from: KINGS, to: HAND, count: 1 }]),
+            None => return Err(MoveError::NothingToDeal),
+        };
+
+        let rank = card.get_numbered_rank();
+        let mut ops = vec![Op::Move { from: HAND, to: PileId::new(PileKind::Foundation, rank), count: 1 }];
+        let next = PileId::new(PileKind::Tableau, rank);
+        if board.get_len(next) > 0 {
+            ops.push(Op::Turn { from: next, to: HAND, count: 1 });
+        }
+
+        Ok(ops)
+    }
+
+    // the game plays itself, one card at a time
+    fn check_take(&self, _board: &Board, from: PileId, _size: usize) -> Result<(), MoveError> {
+        Err(MoveError::CannotMoveFrom(from))
+    }
+
+    fn after_move(&self, _board: &Board) -> Vec<Op> {
+        Vec::new()
+    }
+
+    fn is_safe_on_foundation(&self, _board: &Board, _card: &Card) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::tests::{position, rest_of_deck};
+
+    #[test]
+    fn the_fourth_king_ends_the_game() {
+        let mut piles = vec![
+            "foundation 13 KC KH KS".to_string(),
+            "cell 1 KD".to_string(),
+        ];
+        piles.push(format!("tableau 1 {}", rest_of_deck(Box::new(Clock::new()), &piles)));
+        let mut game = position(Box::new(Clock::new()), &piles);
+
+        assert!(game.deal().is_ok());
+        assert_eq!(game.get_pile_len(PileId::new(PileKind::Foundation, RANK_COUNT)), 4);
+        assert_eq!(game.get_pile_len(HAND), 0);
+        assert!(game.deal().is_err());
+        assert!(!game.is_won());
+        assert!(game.is_stuck());
+    }
+}
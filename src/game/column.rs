@@ -0,0 +1,38 @@
+use crate::game::{MoveError, Pile, PileKind};
+use crate::deck::Card;
+
+// a tableau column nothing is built on, cards only ever leave it
+#[derive(Clone)]
+pub struct Column {
+    stack: Vec<Card>,
+}
+
+impl Column {
+    pub fn new(stack: Vec<Card>) -> Column {
+        Column {
+            stack,
+        }
+    }
+}
+
+impl Pile for Column {
+    fn get_kind(&self) -> PileKind {
+        PileKind::Tableau
+    }
+
+    fn get_cards(&self) -> &Vec<Card> {
+        &self.stack
+    }
+
+    fn get_cards_mut(&mut self) -> &mut Vec<Card> {
+        &mut self.stack
+    }
+
+    fn clone_box(&self) -> Box<dyn Pile> {
+        Box::new(self.clone())
+    }
+
+    fn check_push(&self, _card: &Card) -> Result<(), MoveError> {
+        Err(MoveError::NoBuilding)
+    }
+}
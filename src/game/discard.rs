@@ -0,0 +1,59 @@
+use crate::game::{MoveError, Pile, PileKind, SUITS};
+use crate::deck::{Card, Deck, RANK_COUNT};
+
+// why a card cannot go on one that is not a rank above or below it, going round from King to Ace when `wrap` is set
+pub fn check_next_rank(card: &Card, onto: &Card, wrap: bool) -> Result<(), MoveError> {
+    let (rank, onto_rank) = (card.get_numbered_rank(), onto.get_numbered_rank());
+    let next = rank + 1 == onto_rank || onto_rank + 1 == rank || (wrap && rank.max(onto_rank) - rank.min(onto_rank) == RANK_COUNT - 1);
+
+    if next {
+        Ok(())
+    } else {
+        Err(MoveError::NotNextRank { card: card.clone(), onto: onto.clone() })
+    }
+}
+
+// a foundation that takes cards out of play in no particular order, the game says which ones may go
+#[derive(Clone)]
+pub struct Discard {
+    stack: Vec<Card>,
+}
+
+impl Discard {
+    pub fn new() -> Discard {
+        Discard {
+            stack: Vec::with_capacity(SUITS.len() * RANK_COUNT),
+        }
+    }
+
+    // a discard pile that starts off with the first card of the stock
+    pub fn from_stock(deck: &mut Deck) -> Discard {
+        let mut discard = Discard::new();
+        if let Some(card) = deck.deal(true) {
+            discard.place(card);
+        }
+        discard
+    }
+}
+
+impl Pile for Discard {
+    fn get_kind(&self) -> PileKind {
+        PileKind::Foundation
+    }
+
+    fn get_cards(&self) -> &Vec<Card> {
+        &self.stack
+    }
+
+    fn get_cards_mut(&mut self) -> &mut Vec<Card> {
+        &mut self.stack
+    }
+
+    fn clone_box(&self) -> Box<dyn Pile> {
+        Box::new(self.clone())
+    }
+
+    fn check_push(&self, _card: &Card) -> Result<(), MoveError> {
+        Ok(())
+    }
+}
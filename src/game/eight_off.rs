@@ -0,0 +1,83 @@
+use std::fmt;
+use crate::deck::{Card, Deck};
+use crate::game::board::Board;
+use crate::game::cell::Cell;
+use crate::game::foundation::Foundation;
+use crate::game::render;
+use crate::game::suit_tableau::SuitTableau;
+use crate::game::variant::Variant;
+use crate::game::{Pile, PileKind, Spot, SUITS};
+
+const CELL_COUNT: usize = 8;
+const TABLEAU_COUNT: usize = 8;
+const COLUMN_SIZE: usize = 6;
+
+// eight open columns built down in suit and eight free cells, the last four cards starting off in the cells
+#[derive(Clone)]
+pub struct EightOff;
+
+impl EightOff {
+    pub fn new() -> EightOff {
+        EightOff
+    }
+}
+
+impl Variant for EightOff {
+    fn get_name(&self) -> &'static str {
+        "eightoff"
+    }
+
+    fn clone_box(&self) -> Box<dyn Variant> {
+        Box::new(self.clone())
+    }
+
+    fn get_title(&self) -> String {
+        String::from("Eight Off")
+    }
+
+    fn deal(&self, deal_number: u64) -> Board {
+        let mut deck = Deck::from_seed(deal_number);
+        let columns: Vec<Vec<Card>> = (0..TABLEAU_COUNT)
+            .map(|_| (0..COLUMN_SIZE).map(|_| deck.deal(true).unwrap()).collect())
+            .collect();
+
+        let mut board = Board::new();
+        for _ in 0..CELL_COUNT {
+            let mut cell = Cell::new();
+            if let Some(card) = deck.deal(true) {
+                cell.place(card);
+            }
+            board.add(Box::new(cell));
+        }
+        for suit in SUITS.iter() {
+            board.add(Box::new(Foundation::new(*suit, false)));
+        }
+        for column in columns {
+            board.add(Box::new(SuitTableau::new(column, false)));
+        }
+
+        board
+    }
+
+    fn render(&self, board: &Board, hinted: &dyn Fn(Spot) -> bool, f: &mut fmt::Formatter) -> fmt::Result {
+        let cells = board.ids_of(PileKind::Cell);
+        let names: String = cells.iter().map(|id| format!("x{} ", id.number)).collect();
+        writeln!(f, "{}", names.trim_end())?;
+        for id in cells.iter() {
+            render::write_top(f, board, *id, '\u{00B7}', hinted)?;
+        }
+        write!(f, "\n\n")?;
+
+        for (id, suit) in board.ids_of(PileKind::Foundation).iter().zip(SUITS.iter()) {
+            render::write_top(f, board, *id, suit.get_symbol(), hinted)?;
+        }
+        write!(f, "\n\n")?;
+
+        render::write_columns(f, board, &board.ids_of(PileKind::Tableau), hinted)
+    }
+
+    // the only card that builds on it is the one a rank below in its suit, which is already up
+    fn is_safe_on_foundation(&self, _board: &Board, _card: &Card) -> bool {
+        true
+    }
+}
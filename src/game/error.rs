@@ -0,0 +1,93 @@
+use std::fmt;
+use crate::deck::{Card, Rank, Suit};
+use crate::game::{PileId, PileKind};
+
+// why a move was refused, carrying the cards involved
+#[derive(Clone)]
+pub enum MoveError {
+    NothingToDeal,
+    NoPassesLeft,
+    EmptyPile(PileId),
+    InvalidPile(PileId),
+    SamePile(PileId),
+    CannotMoveFrom(PileId),
+    CannotMoveTo(PileId),
+    NoCards,
+    NotEnoughCards { pile: PileId, size: usize, len: usize },
+    SingleCardFrom(PileId),
+    SingleCardTo(PileId),
+    // face-down cards are never named, that would give them away
+    FaceDown { pile: PileId, size: usize },
+    // `card` sits on `onto` inside the run but does not follow it
+    BrokenRun { card: Card, onto: Card },
+    WrongRank { card: Card, onto: Card },
+    SameColor { card: Card, onto: Card },
+    DifferentSuit { card: Card, onto: Card },
+    WrongSuit { card: Card, suit: Suit },
+    NotKing { card: Card },
+    NotAce { card: Card },
+    NotBaseRank { card: Card, base: Rank },
+    NoRunFits { from: PileId, to: PileId },
+    PartialRun { pile: PileId },
+    FoundationToTableauForbidden,
+    CellTaken,
+    // the run fits but there are not enough free cells and empty columns to carry it over
+    TooManyCards { size: usize, max: usize },
+    NoPile(PileKind),
+    EmptyColumn,
+    CompleteRunsOnly,
+    SlotTaken,
+    // other cards of the layout still lie on it
+    Covered { card: Card },
+    NotThirteen { card: Card, onto: Card },
+    KingsOnly { card: Card },
+    NotNextRank { card: Card, onto: Card },
+    NoBuilding,
+    NoMatch { card: Card, onto: Card },
+    NotOneOrThreeLeft { card: Card },
+    ColumnStaysEmpty,
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MoveError::NothingToDeal => write!(f, "No cards left to deal."),
+            MoveError::NoPassesLeft => write!(f, "No passes through the stock left."),
+            MoveError::EmptyPile(pile) => write!(f, "Nothing to move from {}.", pile),
+            MoveError::InvalidPile(pile) => write!(f, "There is no {}.", pile),
+            MoveError::SamePile(pile) => write!(f, "Cannot move {} onto itself.", pile),
+            MoveError::CannotMoveFrom(pile) => write!(f, "Cannot move cards from {}.", pile),
+            MoveError::CannotMoveTo(pile) => write!(f, "Cannot move to {}.", pile),
+            MoveError::NoCards => write!(f, "Move at least one card."),
+            MoveError::NotEnoughCards { pile, size, len } => write!(f, "Cannot move {} card(s) from {}, it has {}.", size, pile, len),
+            MoveError::SingleCardFrom(pile) => write!(f, "Only the top card of {} can move.", pile),
+            MoveError::SingleCardTo(pile) => write!(f, "Only one card at a time can go on {}.", pile),
+            MoveError::FaceDown { pile, size } => write!(f, "Cannot move {} card(s) from {}, some of them are face-down.", size, pile),
+            MoveError::BrokenRun { card, onto } => write!(f, "{} on {} is not a run, only runs move together.", card.get_name(), onto.get_name()),
+            MoveError::WrongRank { card, onto } => write!(f, "{} cannot go on {}: wrong rank.", card.get_name(), onto.get_name()),
+            MoveError::SameColor { card, onto } => write!(f, "{} cannot go on {}: same color.", card.get_name(), onto.get_name()),
+            MoveError::DifferentSuit { card, onto } => write!(f, "{} cannot go on {}: different suit.", card.get_name(), onto.get_name()),
+            MoveError::WrongSuit { card, suit } => write!(f, "{} cannot go on the {} foundation: wrong suit.", card.get_name(), suit),
+            MoveError::NotKing { card } => write!(f, "{} cannot go on an empty tableau: only Kings can.", card.get_name()),
+            MoveError::NotAce { card } => write!(f, "{} cannot go on an empty foundation: it starts with the Ace.", card.get_name()),
+            MoveError::NotBaseRank { card, base } => write!(f, "{} cannot go on an empty foundation: they start with the {}.", card.get_name(), base.get_code()),
+            MoveError::NoRunFits { from, to } => write!(f, "No run from {} fits on {}.", from, to),
+            MoveError::PartialRun { pile } => write!(f, "Only the whole face-up run of {} can move.", pile),
+            MoveError::FoundationToTableauForbidden => write!(f, "Cards cannot come back off the foundations with these rules."),
+            MoveError::CellTaken => write!(f, "A free cell holds only one card."),
+            MoveError::TooManyCards { size, max } => write!(f, "Only {} card(s) can move at once with the free cells and columns left, not {}.", max, size),
+            MoveError::NoPile(kind) => write!(f, "There is no {} in this game.", kind.get_name()),
+            MoveError::EmptyColumn => write!(f, "Every column needs a card before dealing."),
+            MoveError::CompleteRunsOnly => write!(f, "Only a whole suit from King to Ace goes to the foundations, and it goes by itself."),
+            MoveError::SlotTaken => write!(f, "A card of the layout cannot be built on."),
+            MoveError::Covered { card } => write!(f, "{} is covered by other cards.", card.get_name()),
+            MoveError::NotThirteen { card, onto } => write!(f, "{} and {} do not add up to 13.", card.get_name(), onto.get_name()),
+            MoveError::KingsOnly { card } => write!(f, "{} cannot go out on its own: only Kings can.", card.get_name()),
+            MoveError::NotNextRank { card, onto } => write!(f, "{} cannot go on {}: it has to be one rank above or below.", card.get_name(), onto.get_name()),
+            MoveError::NoBuilding => write!(f, "Nothing can be built on the columns in this game."),
+            MoveError::NoMatch { card, onto } => write!(f, "{} cannot go on {}: neither the suit nor the rank match.", card.get_name(), onto.get_name()),
+            MoveError::NotOneOrThreeLeft { card } => write!(f, "{} can only go on the pile next to it or three to its left.", card.get_name()),
+            MoveError::ColumnStaysEmpty => write!(f, "An empty column stays empty in this game."),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use std::fmt;
+use crate::deck::{Card, Deck};
+use crate::game::board::Board;
+use crate::game::foundation::Foundation;
+use crate::game::render;
+use crate::game::suit_tableau::SuitTableau;
+use crate::game::variant::Variant;
+use crate::game::{PileKind, Spot, SUITS};
+
+const TABLEAU_COUNT: usize = 10;
+
+// ten open columns built up or down in suit, one card at a time
+#[derive(Clone)]
+pub struct Fortress;
+
+impl Fortress {
+    pub fn new() -> Fortress {
+        Fortress
+    }
+}
+
+impl Variant for Fortress {
+    fn get_name(&self) -> &'static str {
+        "fortress"
+    }
+
+    fn clone_box(&self) -> Box<dyn Variant> {
+        Box::new(self.clone())
+    }
+
+    fn get_title(&self) -> String {
+        String::from("Fortress")
+    }
+
+    fn deal(&self, deal_number: u64) -> Board {
+        let mut deck = Deck::from_seed(deal_number);
+        let columns = deck.deal_rows(TABLEAU_COUNT);
+
+        let mut board = Board::new();
+        for suit in SUITS.iter() {
+            board.add(Box::new(Foundation::new(*suit, false)));
+        }
+        for column in columns {
+            board.add(Box::new(SuitTableau::both_ways(column)));
+        }
+
+        board
+    }
+
+    fn render(&self, board: &Board, hinted: &dyn Fn(Spot) -> bool, f: &mut fmt::Formatter) -> fmt::Result {
+        for (id, suit) in board.ids_of(PileKind::Foundation).iter().zip(SUITS.iter()) {
+            render::write_top(f, board, *id, suit.get_symbol(), hinted)?;
+        }
+        write!(f, "\n\n")?;
+
+        render::write_columns(f, board, &board.ids_of(PileKind::Tableau), hinted)
+    }
+
+    // the only cards that build on it are the ones a rank either side in its suit,
+    // and the lower one is already up while the higher one can follow it
+    fn is_safe_on_foundation(&self, _board: &Board, _card: &Card) -> bool {
+        true
+    }
+}
@@ -0,0 +1,87 @@
+use std::fmt;
+use crate::deck::{Card, Deck};
+use crate::game::board::{Board, Op};
+use crate::game::foundation::Foundation;
+use crate::game::render;
+use crate::game::stock::Stock;
+use crate::game::suit_tableau::SuitTableau;
+use crate::game::variant::{self, Variant};
+use crate::game::waste::Waste;
+use crate::game::{MoveError, PileId, PileKind, Spot, SUITS};
+
+const DECKS: usize = 2;
+const TABLEAU_COUNT: usize = 10;
+const COLUMN_SIZE: usize = 4;
+// the stock is gone through once
+const MAX_PASSES: usize = 1;
+
+const STOCK: PileId = PileId { kind: PileKind::Stock, number: 1 };
+const WASTE: PileId = PileId { kind: PileKind::Waste, number: 1 };
+
+// two decks, ten face-up columns built down in suit and moved one card at a time
+#[derive(Clone)]
+pub struct FortyThieves;
+
+impl FortyThieves {
+    pub fn new() -> FortyThieves {
+        FortyThieves
+    }
+}
+
+impl Variant for FortyThieves {
+    fn get_name(&self) -> &'static str {
+        "fortythieves"
+    }
+
+    fn clone_box(&self) -> Box<dyn Variant> {
+        Box::new(self.clone())
+    }
+
+    fn get_title(&self) -> String {
+        String::from("Forty Thieves")
+    }
+
+    fn deal(&self, deal_number: u64) -> Board {
+        let mut deck = Deck::from_seed_with_suits(deal_number, &SUITS, DECKS);
+        let columns: Vec<Vec<Card>> = (0..TABLEAU_COUNT)
+            .map(|_| (0..COLUMN_SIZE).map(|_| deck.deal(true).unwrap()).collect())
+            .collect();
+
+        let mut board = Board::new();
+        board.add(Box::new(Stock::new(deck.cards)));
+        board.add(Box::new(Waste::new()));
+        for _ in 0..DECKS {
+            for suit in SUITS.iter() {
+                board.add(Box::new(Foundation::new(*suit, false)));
+            }
+        }
+        for column in columns {
+            board.add(Box::new(SuitTableau::new(column, true)));
+        }
+
+        board
+    }
+
+    fn render(&self, board: &Board, hinted: &dyn Fn(Spot) -> bool, f: &mut fmt::Formatter) -> fmt::Result {
+        for (id, suit) in board.ids_of(PileKind::Foundation).iter().zip(SUITS.iter().cycle()) {
+            render::write_top(f, board, *id, suit.get_symbol(), hinted)?;
+        }
+        write!(f, " ")?;
+        render::write_waste(f, board, STOCK, WASTE, 1, hinted)?;
+        writeln!(f, " {} card(s) left\n", board.get_len(STOCK))?;
+
+        render::write_columns(f, board, &board.ids_of(PileKind::Tableau), hinted)
+    }
+
+    fn deal_ops(&self, board: &Board) -> Result<Vec<Op>, MoveError> {
+        variant::deal_to_waste(self, board, STOCK, WASTE)
+    }
+
+    fn is_safe_on_foundation(&self, _board: &Board, _card: &Card) -> bool {
+        false
+    }
+
+    fn get_max_passes(&self) -> Option<usize> {
+        Some(MAX_PASSES)
+    }
+}
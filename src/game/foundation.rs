@@ -1,63 +1,107 @@
-use crate::game::{Pile, MAX_TABLEAU_SIZE};
-use crate::deck::{Card, Suit, Rank};
+use crate::game::{MoveError, Pile, PileKind};
+use crate::deck::{Card, Suit, Rank, RANK_COUNT};
 
+#[derive(Clone)]
 pub struct Foundation {
     stack: Vec<Card>,
     suit: Suit,
+    // takes an Ace of any suit and follows that suit from then on
+    any_suit: bool,
+    // the rank the foundation starts with, going round from King to Ace when it is not the Ace
+    base: Rank,
 }
 
 impl Foundation {
-    pub fn new(suit: Suit) -> Foundation {
+    pub fn new(suit: Suit, any_suit: bool) -> Foundation {
         Foundation {
             suit,
-            stack: Vec::with_capacity(MAX_TABLEAU_SIZE),
+            any_suit,
+            base: Rank::Ace,
+            stack: Vec::with_capacity(RANK_COUNT),
         }
     }
-}
 
-impl Pile for Foundation {
-    fn can_push(&self, card: &Card) -> bool {
-        if !card.is_open() {
-            return false;
+    // a foundation of one suit starting from another rank than the Ace
+    pub fn with_base(suit: Suit, base: Rank) -> Foundation {
+        Foundation {
+            base,
+            ..Foundation::new(suit, false)
         }
+    }
 
-        if card.get_suit() != self.suit {
-            return false;
+    // the suit the next card has to be, None when any Ace will do
+    pub fn get_suit(&self) -> Option<Suit> {
+        match self.stack.first() {
+            Some(card) => Some(card.get_suit()),
+            None if self.any_suit => None,
+            None => Some(self.suit),
         }
+    }
+}
 
-        if let Some(last) = self.stack.last() {
-            if last.get_numbered_rank() + 1 != card.get_numbered_rank() {
-                return false;
-            }
-        } else if card.get_rank() != Rank::Ace {
-            return false;
-        };
+impl Pile for Foundation {
+    fn get_kind(&self) -> PileKind {
+        PileKind::Foundation
+    }
 
-        true
+    fn get_cards(&self) -> &Vec<Card> {
+        &self.stack
     }
 
-    fn push(&mut self, card: Card) -> Option<Card> {
-        if self.can_push(&card) {
-            self.stack.push(card);
-            None
-        } else {
-            Some(card)
-        }
+    fn get_cards_mut(&mut self) -> &mut Vec<Card> {
+        &mut self.stack
     }
 
-    fn pop(&mut self) -> Option<Card> {
-        self.stack.pop()
+    fn clone_box(&self) -> Box<dyn Pile> {
+        Box::new(self.clone())
     }
 
-    fn len(&self) -> usize {
-        self.stack.len()
+    fn check_push(&self, card: &Card) -> Result<(), MoveError> {
+        if let Some(suit) = self.get_suit() {
+            if card.get_suit() != suit {
+                return Err(MoveError::WrongSuit { card: card.clone(), suit });
+            }
+        }
+
+        match self.stack.last() {
+            Some(last) => {
+                // a King is followed by an Ace unless the foundation started with the Ace
+                let next = match self.base {
+                    Rank::Ace => last.get_numbered_rank() + 1,
+                    _ => last.get_numbered_rank() % RANK_COUNT + 1,
+                };
+                if card.get_numbered_rank() != next {
+                    return Err(MoveError::WrongRank { card: card.clone(), onto: last.clone() });
+                }
+                Ok(())
+            },
+            None if card.get_rank() == self.base => Ok(()),
+            None if self.base == Rank::Ace => Err(MoveError::NotAce { card: card.clone() }),
+            None => Err(MoveError::NotBaseRank { card: card.clone(), base: self.base.clone() }),
+        }
     }
+}
 
-    fn last(&self) -> Option<&Card> {
-        self.stack.last()
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(code: &str) -> Card {
+        Card::from_code(code).unwrap()
     }
 
-    fn get(&self, i: usize) -> Option<&Card> {
-        self.stack.get(i)
+    #[test]
+    fn a_foundation_started_from_another_rank_goes_round_from_king_to_ace() {
+        let mut foundation = Foundation::with_base(Suit::Hearts, Rank::Queen);
+        assert!(foundation.check_push(&card("JH")).is_err());
+        for code in &["QH", "KH", "AH", "2H"] {
+            assert!(foundation.push(card(code)).is_none());
+        }
+
+        let mut from_ace = Foundation::new(Suit::Hearts, false);
+        for code in &["AH", "2H", "3H", "4H", "5H", "6H", "7H", "8H", "9H", "10H", "JH", "QH", "KH"] {
+            assert!(from_ace.push(card(code)).is_none());
+        }
+        assert!(from_ace.check_push(&card("AH")).is_err());
     }
-}
\ No newline at end of file
+}
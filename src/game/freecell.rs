@@ -0,0 +1,140 @@
+use std::fmt;
+use crate::deck::Deck;
+use crate::game::board::Board;
+use crate::game::cell::Cell;
+use crate::game::foundation::Foundation;
+use crate::game::render;
+use crate::game::tableau::Tableau;
+use crate::game::variant::{self, Variant};
+use crate::game::{MoveError, PileId, PileKind, Spot, SUITS};
+
+const CELL_COUNT: usize = 4;
+const TABLEAU_COUNT: usize = 8;
+// the deals the original game came with
+const MAX_DEAL_NUMBER: u64 = 32_000;
+
+// every card is dealt face-up, the free cells make up for the missing stock
+#[derive(Clone)]
+pub struct FreeCell;
+
+impl FreeCell {
+    pub fn new() -> FreeCell {
+        FreeCell
+    }
+
+    // the longest run that can be carried over one card at a time through the
+    // free cells and the empty columns other than the destination
+    fn max_run(&self, board: &Board, to: PileId) -> usize {
+        let cells = board.ids_of(PileKind::Cell)
+            .into_iter()
+            .filter(|id| board.get_len(*id) == 0)
+            .count();
+        let columns = board.ids_of(PileKind::Tableau)
+            .into_iter()
+            .filter(|id| *id != to && board.get_len(*id) == 0)
+            .count();
+
+        (cells + 1) << columns
+    }
+}
+
+impl Variant for FreeCell {
+    fn get_name(&self) -> &'static str {
+        "freecell"
+    }
+
+    fn clone_box(&self) -> Box<dyn Variant> {
+        Box::new(self.clone())
+    }
+
+    fn get_title(&self) -> String {
+        String::from("FreeCell")
+    }
+
+    fn deal(&self, deal_number: u64) -> Board {
+        let mut deck = Deck::from_microsoft_deal(deal_number);
+        let columns = deck.deal_rows(TABLEAU_COUNT);
+
+        let mut board = Board::new();
+        for _ in 0..CELL_COUNT {
+            board.add(Box::new(Cell::new()));
+        }
+        for suit in SUITS.iter() {
+            board.add(Box::new(Foundation::new(*suit, false)));
+        }
+        for column in columns {
+            board.add(Box::new(Tableau::new(column, true)));
+        }
+
+        board
+    }
+
+    fn render(&self, board: &Board, hinted: &dyn Fn(Spot) -> bool, f: &mut fmt::Formatter) -> fmt::Result {
+        let cells = board.ids_of(PileKind::Cell);
+        let names: String = cells.iter().map(|id| format!("x{} ", id.number)).collect();
+        writeln!(f, "{}", names.trim_end())?;
+
+        for id in cells.iter() {
+            render::write_top(f, board, *id, '\u{00B7}', hinted)?;
+        }
+        for (id, suit) in board.ids_of(PileKind::Foundation).iter().zip(SUITS.iter()) {
+            render::write_top(f, board, *id, suit.get_symbol(), hinted)?;
+        }
+        write!(f, "\n\n")?;
+
+        render::write_columns(f, board, &board.ids_of(PileKind::Tableau), hinted)
+    }
+
+    fn check_move(&self, board: &Board, from: PileId, to: PileId, size: usize) -> Result<(), MoveError> {
+        variant::check_pile_move(self, board, from, to, size)?;
+
+        let max = self.max_run(board, to);
+        if size > max {
+            return Err(MoveError::TooManyCards { size, max });
+        }
+
+        Ok(())
+    }
+
+    fn get_max_deal_number(&self) -> Option<u64> {
+        Some(MAX_DEAL_NUMBER)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deck::Card;
+    use crate::game::Pile;
+
+    // four free cells, the first `cells_taken` of them holding a card, and eight columns
+    // of which the last `empty_columns` are empty
+    fn board(cells_taken: usize, empty_columns: usize) -> Board {
+        let mut board = Board::new();
+        for i in 0..CELL_COUNT {
+            let mut cell = Cell::new();
+            if i < cells_taken {
+                cell.place(Card::from_code("2H").unwrap());
+            }
+            board.add(Box::new(cell));
+        }
+        for i in 0..TABLEAU_COUNT {
+            let column = if i < TABLEAU_COUNT - empty_columns { vec![Card::from_code("KS").unwrap()] } else { Vec::new() };
+            board.add(Box::new(Tableau::new(column, true)));
+        }
+        board
+    }
+
+    #[test]
+    fn max_run_counts_free_cells_and_empty_columns() {
+        let freecell = FreeCell::new();
+        let to_column = PileId::new(PileKind::Tableau, 1);
+        let to_empty_column = PileId::new(PileKind::Tableau, TABLEAU_COUNT);
+
+        assert_eq!(freecell.max_run(&board(0, 0), to_column), 5);
+        assert_eq!(freecell.max_run(&board(4, 0), to_column), 1);
+        assert_eq!(freecell.max_run(&board(1, 2), to_column), 16);
+        // the column moved to does not count
+        assert_eq!(freecell.max_run(&board(1, 2), to_empty_column), 8);
+    }
+}
@@ -0,0 +1,131 @@
+use std::fmt;
+use crate::deck::{Card, Deck};
+use crate::game::board::{Board, Op};
+use crate::game::column::Column;
+use crate::game::discard::{self, Discard};
+use crate::game::render;
+use crate::game::stock::Stock;
+use crate::game::variant::{self, Variant};
+use crate::game::{MoveError, PileId, PileKind, Spot};
+
+const TABLEAU_COUNT: usize = 7;
+const COLUMN_SIZE: usize = 5;
+
+const STOCK: PileId = PileId { kind: PileKind::Stock, number: 1 };
+const FOUNDATION: PileId = PileId { kind: PileKind::Foundation, number: 1 };
+
+// seven face-up columns played off onto a single foundation, one rank up or down from its top card
+#[derive(Clone)]
+pub struct Golf;
+
+impl Golf {
+    pub fn new() -> Golf {
+        Golf
+    }
+}
+
+impl Variant for Golf {
+    fn get_name(&self) -> &'static str {
+        "golf"
+    }
+
+    fn clone_box(&self) -> Box<dyn Variant> {
+        Box::new(self.clone())
+    }
+
+    fn get_title(&self) -> String {
+        String::from("Golf")
+    }
+
+    fn deal(&self, deal_number: u64) -> Board {
+        let mut deck = Deck::from_seed(deal_number);
+        let columns: Vec<Vec<Card>> = (0..TABLEAU_COUNT)
+            .map(|_| (0..COLUMN_SIZE).map(|_| deck.deal(true).unwrap()).collect())
+            .collect();
+
+        let foundation = Discard::from_stock(&mut deck);
+
+        let mut board = Board::new();
+        board.add(Box::new(Stock::new(deck.cards)));
+        board.add(Box::new(foundation));
+        for column in columns {
+            board.add(Box::new(Column::new(column)));
+        }
+
+        board
+    }
+
+    fn render(&self, board: &Board, hinted: &dyn Fn(Spot) -> bool, f: &mut fmt::Formatter) -> fmt::Result {
+        render::write_top(f, board, FOUNDATION, '\u{00B7}', hinted)?;
+        write!(f, "  ")?;
+        render::write_top(f, board, STOCK, '\u{1F0EA}', hinted)?;
+        write!(f, " {} card(s) left\n\n", board.get_len(STOCK))?;
+
+        render::write_columns(f, board, &board.ids_of(PileKind::Tableau), hinted)
+    }
+
+    fn deal_ops(&self, board: &Board) -> Result<Vec<Op>, MoveError> {
+        if board.get_len(STOCK) == 0 {
+            return Err(MoveError::NothingToDeal);
+        }
+
+        Ok(vec![Op::Turn { from: STOCK, to: FOUNDATION, count: 1 }])
+    }
+
+    fn check_take(&self, board: &Board, from: PileId, size: usize) -> Result<(), MoveError> {
+        if from.kind == PileKind::Foundation {
+            return Err(MoveError::CannotMoveFrom(from));
+        }
+
+        variant::check_pile_take(board, from, size)
+    }
+
+    // a rank up or down, with nothing going round the corner from King to Ace
+    fn check_put(&self, board: &Board, to: PileId, card: &Card, size: usize) -> Result<(), MoveError> {
+        if to.kind != PileKind::Foundation {
+            return variant::check_pile_put(board, to, card, size);
+        }
+
+        match board.last(to) {
+            Some(onto) => discard::check_next_rank(card, onto, false),
+            None => Ok(()),
+        }
+    }
+
+    // the columns cleared, whatever is left in the stock
+    fn is_won(&self, board: &Board) -> bool {
+        board.count(PileKind::Tableau) == 0
+    }
+
+    fn is_safe_on_foundation(&self, _board: &Board, _card: &Card) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::tests::{position, rest_of_deck};
+    use crate::game::Move;
+
+    fn column(number: usize) -> PileId {
+        PileId::new(PileKind::Tableau, number)
+    }
+
+    #[test]
+    fn a_card_a_rank_either_side_goes_up_but_never_round_from_king_to_ace() {
+        let mut piles = vec![
+            "foundation 1 KH".to_string(),
+            "tableau 1 AS".to_string(),
+            "tableau 2 QD".to_string(),
+            "tableau 3 JC".to_string(),
+        ];
+        piles.push(format!("stock {}", rest_of_deck(Box::new(Golf::new()), &piles)));
+        let mut game = position(Box::new(Golf::new()), &piles);
+
+        assert!(game.play(Move::Transfer(column(1), FOUNDATION, 1)).is_err());
+        assert!(game.play(Move::Transfer(column(2), FOUNDATION, 1)).is_ok());
+        assert!(game.play(Move::Transfer(column(3), FOUNDATION, 1)).is_ok());
+        assert_eq!(game.get_pile_len(FOUNDATION), 3);
+    }
+}
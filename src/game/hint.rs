@@ -0,0 +1,105 @@
+use std::cmp::Reverse;
+use crate::deck::Rank;
+use crate::game::{Game, Move, PileId, PileKind};
+
+impl Game {
+    fn count_hidden(&self, id: PileId) -> u32 {
+        self.board.get_cards(id)
+            .iter()
+            .filter(|card| !card.is_open())
+            .count() as u32
+    }
+
+    // with every tableau card face-up nothing is left to chance
+    pub fn can_autocomplete(&self) -> bool {
+        !self.is_won() && self.board.get_ids()
+            .iter()
+            .filter(|id| matches!(id.kind, PileKind::Tableau | PileKind::Slot))
+            .all(|id| self.count_hidden(*id) == 0)
+    }
+
+    // how promising a move looks, None for moves not worth suggesting
+    pub fn rate_move(&self, mv: &Move) -> Option<u32> {
+        if *mv != Move::Deal && !self.is_progress(mv) {
+            return None;
+        }
+
+        let (from, to, size) = match *mv {
+            Move::Deal => return Some(1),
+            Move::Transfer(from, to, size) => (from, to, size),
+        };
+
+        match (from.kind, to.kind) {
+            (PileKind::Waste, PileKind::Foundation) => Some(50),
+            (PileKind::Waste, PileKind::Tableau) => {
+                let king = self.board.last(from).is_some_and(|card| card.get_rank() == Rank::King);
+                if king && self.board.get_len(to) == 0 {
+                    Some(35)
+                } else {
+                    Some(30)
+                }
+            },
+            (PileKind::Tableau, PileKind::Foundation) => {
+                if self.will_flip(from, 1) {
+                    Some(100 + self.count_hidden(from))
+                } else {
+                    Some(60)
+                }
+            },
+            (PileKind::Tableau, PileKind::Tableau) => {
+                let len = self.board.get_len(from);
+
+                // with no foundations to play to, gathering the piles together is the game
+                if self.board.first_of(PileKind::Foundation).is_none() {
+                    return Some(40);
+                }
+
+                // uncovering the columns with the most face-down cards first pays off the most
+                if self.will_flip(from, size) {
+                    let king_bonus = if self.board.get_len(to) == 0 { 10 } else { 0 };
+                    return Some(70 + king_bonus + self.count_hidden(from));
+                }
+
+                if size == len {
+                    return Some(40);
+                }
+
+                // moving the run away lets the card under it go to its foundation
+                let under = self.board.get_card(from, len - size - 1)?;
+                if self.foundation_for(from, under).is_some() {
+                    return Some(35);
+                }
+
+                None
+            },
+            // taking out the cards of a layout uncovers the ones under them
+            (PileKind::Slot, _) => Some(60),
+            (PileKind::Waste, PileKind::Slot) => Some(50),
+            // every card off the reserve brings the next one within reach
+            (PileKind::Reserve, PileKind::Foundation) => Some(65),
+            (PileKind::Reserve, PileKind::Tableau) => Some(55),
+            (PileKind::Cell, PileKind::Foundation) => Some(55),
+            (PileKind::Cell, PileKind::Tableau) => Some(45),
+            // parking a card costs a free cell, worth it when it frees the card under it
+            (PileKind::Tableau, PileKind::Cell) => {
+                let len = self.board.get_len(from);
+                match len.checked_sub(2).and_then(|i| self.board.get_card(from, i)) {
+                    Some(under) if self.foundation_for(from, under).is_some() => Some(35),
+                    _ => Some(5),
+                }
+            },
+            _ => None,
+        }
+    }
+
+    // legal moves worth playing, the most promising first
+    pub fn hints(&self) -> Vec<Move> {
+        let mut rated: Vec<(u32, Move)> = self.legal_moves()
+            .into_iter()
+            .filter_map(|mv| self.rate_move(&mv).map(|rating| (rating, mv)))
+            .collect();
+
+        rated.sort_by_key(|(rating, _)| Reverse(*rating));
+        rated.into_iter().map(|(_, mv)| mv).collect()
+    }
+}
@@ -0,0 +1,120 @@
+use std::fmt;
+use crate::game::board::Op;
+use crate::game::{PileId, PileKind};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Move {
+    Deal,
+    // `size` cards off the top of one pile onto another
+    Transfer(PileId, PileId, usize),
+}
+
+impl Move {
+    // the kinds of pile the cards went from and to
+    pub fn get_kinds(&self) -> Option<(PileKind, PileKind)> {
+        match self {
+            Move::Deal => None,
+            Move::Transfer(from, to, _) => Some((from.kind, to.kind)),
+        }
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Move::Deal => write!(f, "deal"),
+            Move::Transfer(from, to, 1) => write!(f, "{} to {}", from, to),
+            Move::Transfer(from, to, size) => write!(f, "{} card(s) from {} to {}", size, from, to),
+        }
+    }
+}
+
+// everything needed to take a move back
+#[derive(Clone)]
+pub struct Record {
+    pub mv: Move,
+    // the steps the move made on the board, the automatic ones after it included
+    pub ops: Vec<Op>,
+    // the move turned a face-down card up
+    pub flipped: bool,
+    // cards that went to the foundations without being moved there, like Spider's complete runs
+    pub collected: usize,
+    // the deal turned the waste back into the stock
+    pub recycled: bool,
+    // what the move did to the score
+    pub points: i32,
+}
+
+// how many moves of each kind were played, undone ones included
+#[derive(Clone, Default)]
+pub struct MoveCounts {
+    pub deals: usize,
+    pub recycles: usize,
+    pub waste_to_foundation: usize,
+    pub waste_to_tableau: usize,
+    pub tableau_to_foundation: usize,
+    pub tableau_to_tableau: usize,
+    pub foundation_to_tableau: usize,
+    pub undos: usize,
+}
+
+impl MoveCounts {
+    pub fn count(&mut self, record: &Record) {
+        match record.mv.get_kinds() {
+            None if record.recycled => self.recycles += 1,
+            None => self.deals += 1,
+            Some((PileKind::Waste, PileKind::Foundation)) => self.waste_to_foundation += 1,
+            Some((PileKind::Waste, _)) => self.waste_to_tableau += 1,
+            Some((_, PileKind::Foundation)) => self.tableau_to_foundation += 1,
+            Some((PileKind::Foundation, _)) => self.foundation_to_tableau += 1,
+            Some(_) => self.tableau_to_tableau += 1,
+        }
+    }
+
+    // every move played, not counting undos
+    pub fn total(&self) -> usize {
+        self.deals
+            + self.recycles
+            + self.waste_to_foundation
+            + self.waste_to_tableau
+            + self.tableau_to_foundation
+            + self.tableau_to_tableau
+            + self.foundation_to_tableau
+    }
+}
+
+#[derive(Clone)]
+pub struct History {
+    done: Vec<Record>,
+    undone: Vec<Move>,
+}
+
+impl History {
+    pub fn new() -> History {
+        History {
+            done: Vec::new(),
+            undone: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, record: Record) {
+        self.done.push(record);
+        self.undone.clear();
+    }
+
+    pub fn pop(&mut self) -> Option<Record> {
+        let record = self.done.pop()?;
+        self.undone.push(record.mv);
+        Some(record)
+    }
+
+    // the move a redo would play, left in place until it is played
+    pub fn last_undone(&self) -> Option<Move> {
+        self.undone.last().copied()
+    }
+
+    pub fn push_redone(&mut self, record: Record) {
+        self.undone.pop();
+        self.done.push(record);
+    }
+}
@@ -0,0 +1,205 @@
+use std::fmt;
+use crate::deck::{Card, Deck};
+use crate::game::board::{Board, Op};
+use crate::game::foundation::Foundation;
+use crate::game::render;
+use crate::game::stock::Stock;
+use crate::game::tableau::Tableau;
+use crate::game::variant::{self, Variant};
+use crate::game::waste::Waste;
+use crate::game::{KlondikeRules, MoveError, PassLimit, PileId, PileKind, Spot, MAX_DRAW_COUNT, MIN_DRAW_COUNT, SUITS};
+
+const TABLEAU_COUNT: usize = 7;
+// the waste shows this many of its top cards
+const WASTE_FAN: usize = 3;
+
+const STOCK: PileId = PileId { kind: PileKind::Stock, number: 1 };
+const WASTE: PileId = PileId { kind: PileKind::Waste, number: 1 };
+
+// house rules that differ from the defaults, written by name
+fn house_rule_names(rules: &KlondikeRules) -> Vec<&'static str> {
+    let mut names = Vec::new();
+    if rules.any_card_on_empty_column {
+        names.push("any-card-on-empty");
+    }
+    if !rules.foundation_to_tableau {
+        names.push("no-foundation-to-tableau");
+    }
+    if rules.any_suit_foundations {
+        names.push("any-suit-foundations");
+    }
+    if !rules.partial_runs {
+        names.push("whole-runs-only");
+    }
+
+    names
+}
+
+#[derive(Clone)]
+pub struct Klondike {
+    rules: KlondikeRules,
+}
+
+impl Klondike {
+    pub fn new(rules: KlondikeRules) -> Klondike {
+        Klondike {
+            rules,
+        }
+    }
+
+    // reads back the options written by `write_options`, house rules that are not named keep their usual setting
+    pub fn from_options(lines: &[&str]) -> Result<Klondike, String> {
+        let mut rules = KlondikeRules::default();
+        let mut has_deal_size = false;
+        let mut has_stock_rules = false;
+
+        for line in lines {
+            let mut tokens = line.split_whitespace();
+
+            match tokens.next() {
+                Some("deal-size") => {
+                    rules.draw_count = match tokens.next().and_then(|number| number.parse().ok()) {
+                        Some(size) if (MIN_DRAW_COUNT..=MAX_DRAW_COUNT).contains(&size) => size,
+                        _ => return Err(String::from("The deal size is not valid.")),
+                    };
+                    has_deal_size = true;
+                },
+                Some("stock-rules") => {
+                    rules.pass_limit = tokens.next()
+                        .and_then(PassLimit::from_name)
+                        .ok_or_else(|| String::from("The pass limit is not valid."))?;
+                    rules.redeal_penalty = match tokens.next() {
+                        Some("penalty") => true,
+                        Some("no-penalty") => false,
+                        _ => return Err(String::from("The redeal penalty is not valid.")),
+                    };
+                    has_stock_rules = true;
+                },
+                Some("house-rules") => {
+                    for name in tokens {
+                        match name {
+                            "any-card-on-empty" => rules.any_card_on_empty_column = true,
+                            "no-foundation-to-tableau" => rules.foundation_to_tableau = false,
+                            "any-suit-foundations" => rules.any_suit_foundations = true,
+                            "whole-runs-only" => rules.partial_runs = false,
+                            _ => return Err(format!("Unknown house rule {} in save file.", name)),
+                        }
+                    }
+                },
+                Some(key) => return Err(format!("Unknown entry {} in save file.", key)),
+                None => continue,
+            }
+        }
+
+        if !has_deal_size {
+            return Err(String::from("The save file has no deal size."));
+        }
+        if !has_stock_rules {
+            return Err(String::from("The save file has no stock rules."));
+        }
+
+        Ok(Klondike::new(rules))
+    }
+
+    // whether moving the top `size` cards would leave part of their face-up run behind
+    fn is_partial_run(&self, board: &Board, tableau: PileId, size: usize) -> bool {
+        if self.rules.partial_runs {
+            return false;
+        }
+
+        let len = board.get_len(tableau);
+        len > size && board.get_card(tableau, len - size - 1).is_some_and(|card| card.is_open())
+    }
+}
+
+impl Variant for Klondike {
+    fn get_name(&self) -> &'static str {
+        "klondike"
+    }
+
+    fn clone_box(&self) -> Box<dyn Variant> {
+        Box::new(self.clone())
+    }
+
+    fn get_title(&self) -> String {
+        format!("Klondike draw {}", self.rules.draw_count)
+    }
+
+    fn deal(&self, deal_number: u64) -> Board {
+        let mut deck = Deck::from_seed(deal_number);
+
+        // each column gets one more card than the last, only the top one face-up
+        let columns: Vec<Vec<Card>> = (1..=TABLEAU_COUNT)
+            .map(|size| (1..=size).map(|i| deck.deal(i == size).unwrap()).collect())
+            .collect();
+
+        let mut board = Board::new();
+        board.add(Box::new(Stock::new(deck.cards)));
+        board.add(Box::new(Waste::new()));
+        for suit in SUITS.iter() {
+            board.add(Box::new(Foundation::new(*suit, self.rules.any_suit_foundations)));
+        }
+        for column in columns {
+            board.add(Box::new(Tableau::new(column, self.rules.any_card_on_empty_column)));
+        }
+
+        board
+    }
+
+    fn render(&self, board: &Board, hinted: &dyn Fn(Spot) -> bool, f: &mut fmt::Formatter) -> fmt::Result {
+        for (id, suit) in board.ids_of(PileKind::Foundation).iter().zip(SUITS.iter()) {
+            render::write_top(f, board, *id, suit.get_symbol(), hinted)?;
+        }
+
+        render::write_waste(f, board, STOCK, WASTE, WASTE_FAN, hinted)?;
+        write!(f, "\n\n")?;
+
+        render::write_columns(f, board, &board.ids_of(PileKind::Tableau), hinted)
+    }
+
+    fn write_options(&self) -> String {
+        let mut out = format!("deal-size {}\n", self.rules.draw_count);
+        out.push_str(&format!(
+            "stock-rules {} {}\n",
+            self.rules.pass_limit,
+            if self.rules.redeal_penalty { "penalty" } else { "no-penalty" },
+        ));
+
+        let house_rules = house_rule_names(&self.rules);
+        if !house_rules.is_empty() {
+            out.push_str(&format!("house-rules {}\n", house_rules.join(" ")));
+        }
+
+        out
+    }
+
+    fn deal_ops(&self, board: &Board) -> Result<Vec<Op>, MoveError> {
+        variant::deal_to_waste(self, board, STOCK, WASTE)
+    }
+
+    fn check_take(&self, board: &Board, from: PileId, size: usize) -> Result<(), MoveError> {
+        if from.kind == PileKind::Foundation && !self.rules.foundation_to_tableau {
+            return Err(MoveError::FoundationToTableauForbidden);
+        }
+
+        variant::check_pile_take(board, from, size)?;
+
+        if from.kind == PileKind::Tableau && self.is_partial_run(board, from, size) {
+            return Err(MoveError::PartialRun { pile: from });
+        }
+
+        Ok(())
+    }
+
+    fn get_draw_count(&self) -> usize {
+        self.rules.draw_count
+    }
+
+    fn get_max_passes(&self) -> Option<usize> {
+        self.rules.pass_limit.max_passes()
+    }
+
+    fn has_redeal_penalty(&self) -> bool {
+        self.rules.redeal_penalty
+    }
+}
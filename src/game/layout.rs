@@ -0,0 +1,113 @@
+use std::fmt;
+use crate::game::board::{Board, Op};
+use crate::game::render;
+use crate::game::{MoveError, PileId, PileKind, Spot};
+
+// where one card of a layout lies
+#[derive(Clone)]
+struct Place {
+    row: usize,
+    // across the table, in half card widths
+    x: usize,
+    // the places lying on this one
+    covered_by: Vec<usize>,
+}
+
+// cards dealt in overlapping rows onto slots, each card held down by the ones lying on it
+#[derive(Clone)]
+pub struct Layout {
+    places: Vec<Place>,
+}
+
+fn slot(i: usize) -> PileId {
+    PileId::new(PileKind::Slot, i + 1)
+}
+
+impl Layout {
+    // rows of one, two and so on cards, each card covering half of the two below it
+    pub fn pyramid(rows: usize) -> Layout {
+        let start = |row: usize| row * (row + 1) / 2;
+        let places = (0..rows)
+            .flat_map(|row| (0..=row).map(move |column| (row, column)))
+            .map(|(row, column)| Place {
+                row,
+                x: rows - 1 - row + 2 * column,
+                covered_by: if row + 1 < rows { vec![start(row + 1) + column, start(row + 1) + column + 1] } else { Vec::new() },
+            })
+            .collect();
+
+        Layout {
+            places,
+        }
+    }
+
+    // three small pyramids of three rows side by side, standing on a row of ten cards
+    pub fn tri_peaks() -> Layout {
+        let mut places = Vec::new();
+        for peak in 0..3 {
+            places.push(Place { row: 0, x: 6 * peak + 3, covered_by: vec![3 + 2 * peak, 4 + 2 * peak] });
+        }
+        for i in 0..6 {
+            let (peak, side) = (i / 2, i % 2);
+            places.push(Place { row: 1, x: 6 * peak + 2 * side + 2, covered_by: vec![9 + 3 * peak + side, 10 + 3 * peak + side] });
+        }
+        for i in 0..9 {
+            places.push(Place { row: 2, x: 2 * i + 1, covered_by: vec![18 + i, 19 + i] });
+        }
+        for i in 0..10 {
+            places.push(Place { row: 3, x: 2 * i, covered_by: Vec::new() });
+        }
+
+        Layout {
+            places,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.places.len()
+    }
+
+    // the row of the slot a card of the deal goes on, counting from the top
+    pub fn get_row(&self, i: usize) -> usize {
+        self.places[i].row
+    }
+
+    pub fn is_covered(&self, board: &Board, id: PileId) -> bool {
+        id.kind == PileKind::Slot
+            && self.places.get(id.number - 1).is_some_and(|place| place.covered_by.iter().any(|i| board.get_len(slot(*i)) > 0))
+    }
+
+    // why the card of a slot cannot be played yet
+    pub fn check_uncovered(&self, board: &Board, id: PileId) -> Result<(), MoveError> {
+        match board.last(id) {
+            Some(card) if self.is_covered(board, id) => Err(MoveError::Covered { card: card.clone() }),
+            _ => Ok(()),
+        }
+    }
+
+    // turns up the face-down cards nothing lies on anymore
+    pub fn flip_uncovered(&self, board: &Board) -> Vec<Op> {
+        (0..self.len())
+            .map(slot)
+            .filter(|id| board.last(*id).is_some_and(|card| !card.is_open()) && !self.is_covered(board, *id))
+            .map(Op::Flip)
+            .collect()
+    }
+
+    pub fn write(&self, f: &mut fmt::Formatter, board: &Board, hinted: &dyn Fn(Spot) -> bool) -> fmt::Result {
+        let rows = self.places.iter().map(|place| place.row + 1).max().unwrap_or(0);
+
+        for row in 0..rows {
+            let mut width = 0;
+            for (i, place) in self.places.iter().enumerate().filter(|(_, place)| place.row == row) {
+                // a card is two characters wide with the gap after it, four with its hint marks
+                write!(f, "{:1$}", "", 2 * place.x - width)?;
+                render::write_card(f, board.last(slot(i)), ' ', hinted(Spot { pile: slot(i), index: 0 }))?;
+                width = 2 * place.x + 3;
+            }
+            writeln!(f)?;
+        }
+
+        writeln!(f)
+    }
+}
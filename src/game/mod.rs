@@ -1,432 +1,854 @@
 use std::fmt;
-use crate::deck::{Deck, Card, Suit};
-
+use std::time::Duration;
+use rand::Rng;
+use crate::deck::{Card, Rank, Suit};
+
+mod pile;
+mod board;
+mod variant;
+mod render;
+mod klondike;
+mod freecell;
+mod cell;
+mod spider;
+mod spider_tableau;
+mod run_foundation;
+mod yukon;
+mod yukon_tableau;
+mod pyramid;
+mod tri_peaks;
+mod layout;
+mod slot;
+mod discard;
+mod column;
+mod golf;
+mod accordion;
+mod clock;
+mod canfield;
+mod canfield_tableau;
+mod forty_thieves;
+mod suit_tableau;
+mod reserve;
+mod bakers_dozen;
+mod beleaguered_castle;
+mod fortress;
+mod eight_off;
+mod rank_tableau;
 mod tableau;
 mod foundation;
 mod waste;
 mod stock;
-
-use tableau::Tableau;
-use foundation::Foundation;
-use waste::Waste;
-use stock::Stock;
-
-trait Pile {
-    fn can_push(&self, t: &Card) -> bool;
-    fn push(&mut self, t: Card) -> Option<Card>;
-    fn pop(&mut self) -> Option<Card>;
-    fn len(&self) -> usize;
-    fn last(&self) -> Option<&Card>;
-    fn get(&self, i: usize) -> Option<&Card>;
+mod history;
+mod save;
+mod hint;
+mod score;
+mod timer;
+mod error;
+mod rules;
+
+use board::{Board, Op};
+use history::{History, Record};
+use timer::Timer;
+
+pub use pile::{Pile, PileId, PileKind};
+pub use variant::Variant;
+pub use klondike::Klondike;
+pub use freecell::FreeCell;
+pub use spider::Spider;
+pub use yukon::Yukon;
+pub use pyramid::Pyramid;
+pub use tri_peaks::TriPeaks;
+pub use golf::Golf;
+pub use accordion::Accordion;
+pub use clock::Clock;
+pub use canfield::Canfield;
+pub use forty_thieves::FortyThieves;
+pub use bakers_dozen::BakersDozen;
+pub use beleaguered_castle::BeleagueredCastle;
+pub use fortress::Fortress;
+pub use eight_off::EightOff;
+pub use history::{Move, MoveCounts};
+pub use score::Scoring;
+pub use error::MoveError;
+pub use rules::{KlondikeRules, PassLimit, MIN_DRAW_COUNT, MAX_DRAW_COUNT};
+
+const SUITS: [Suit; 4] = [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades];
+const MAX_RANDOM_DEAL_NUMBER: u64 = 1_000_000;
+
+// a deal number up to the highest one a game knows, if it has one
+pub fn random_deal_number(max: Option<u64>) -> u64 {
+    rand::thread_rng().gen_range(1, max.map_or(MAX_RANDOM_DEAL_NUMBER, |max| max + 1))
 }
 
-const MAX_TABLEAU_SIZE: usize = 13;
-
-pub enum DealSize {
-    One,
-    Three,
+// a place on the board a card can be seen at
+#[derive(Clone, Copy, PartialEq)]
+pub struct Spot {
+    pub pile: PileId,
+    pub index: usize,
 }
 
+#[derive(Clone)]
 pub struct Game {
-    tableau_1: Tableau,
-    tableau_2: Tableau,
-    tableau_3: Tableau,
-    tableau_4: Tableau,
-    tableau_5: Tableau,
-    tableau_6: Tableau,
-    tableau_7: Tableau,
-
-    clubs_foundation: Foundation,
-    diamonds_foundation: Foundation,
-    hearts_foundation: Foundation,
-    spades_foundation: Foundation,
-
-    waste: Waste,
-    stock: Stock,
-
-    deal_size: DealSize
+    variant: Box<dyn Variant>,
+    board: Board,
+    deal_number: u64,
+
+    scoring: Scoring,
+    score: i32,
+    // Vegas money carried over from earlier games
+    bankroll: i32,
+
+    history: History,
+    move_counts: MoveCounts,
+    timer: Timer,
+
+    // the move to point out on screen
+    hint: Option<Move>,
 }
 
 impl Game {
-    pub fn new(deal_size: DealSize) -> Game {
-        let mut deck = Deck::new();
-
+    pub fn new(variant: Box<dyn Variant>, deal_number: u64, scoring: Scoring) -> Game {
         Game {
-            tableau_1: Tableau::new(vec![deck.deal(true).unwrap()]),
-            tableau_2: Tableau::new(vec![deck.deal(false).unwrap(), deck.deal(true).unwrap()]),
-            tableau_3: Tableau::new(vec![deck.deal(false).unwrap(), deck.deal(false).unwrap(), deck.deal(true).unwrap()]),
-            tableau_4: Tableau::new(vec![deck.deal(false).unwrap(), deck.deal(false).unwrap(), deck.deal(false).unwrap(), deck.deal(true).unwrap()]),
-            tableau_5: Tableau::new(vec![deck.deal(false).unwrap(), deck.deal(false).unwrap(), deck.deal(false).unwrap(), deck.deal(false).unwrap(), deck.deal(true).unwrap()]),
-            tableau_6: Tableau::new(vec![deck.deal(false).unwrap(), deck.deal(false).unwrap(), deck.deal(false).unwrap(), deck.deal(false).unwrap(), deck.deal(false).unwrap(), deck.deal(true).unwrap()]),
-            tableau_7: Tableau::new(vec![deck.deal(false).unwrap(), deck.deal(false).unwrap(), deck.deal(false).unwrap(), deck.deal(false).unwrap(), deck.deal(false).unwrap(), deck.deal(false).unwrap(), deck.deal(true).unwrap()]),
-
-            clubs_foundation: Foundation::new(Suit::Clubs),
-            diamonds_foundation: Foundation::new(Suit::Diamonds),
-            hearts_foundation: Foundation::new(Suit::Hearts),
-            spades_foundation: Foundation::new(Suit::Spades),
-
-            waste: Waste::new(),
-            stock: Stock::new(deck.cards),
-
-            deal_size
+            board: variant.deal(deal_number),
+            variant,
+            deal_number,
+
+            scoring,
+            score: scoring.initial_score(),
+            bankroll: 0,
+
+            history: History::new(),
+            move_counts: MoveCounts::default(),
+            timer: Timer::new(Duration::from_secs(0)),
+
+            hint: None,
         }
     }
 
-    // pop methods
+    // get methods
 
-    fn pop_from_tableau(&mut self, tableau_number: usize) -> Option<Card> {
-        match tableau_number {
-            1 => self.tableau_1.pop(),
-            2 => self.tableau_2.pop(),
-            3 => self.tableau_3.pop(),
-            4 => self.tableau_4.pop(),
-            5 => self.tableau_5.pop(),
-            6 => self.tableau_6.pop(),
-            7 => self.tableau_7.pop(),
-            _ => None
-        }
+    pub fn get_pile_len(&self, id: PileId) -> usize {
+        self.board.get_len(id)
     }
 
-    fn pop_from_foundation(&mut self, foundation_suit: Suit) -> Option<Card> {
-        match foundation_suit {
-            Suit::Clubs => self.clubs_foundation.pop(),
-            Suit::Diamonds => self.diamonds_foundation.pop(),
-            Suit::Hearts => self.hearts_foundation.pop(),
-            Suit::Spades => self.spades_foundation.pop(),
-        }
+    pub fn get_title(&self) -> String {
+        self.variant.get_title()
     }
 
-    fn pop_from_waste(&mut self) -> Option<Card> {
-        self.waste.pop()
+    pub fn get_deal_size(&self) -> usize {
+        self.variant.get_draw_count()
     }
 
-    fn pop_from_stock(&mut self) -> Option<Card> {
-        if let Some(mut card) = self.stock.pop() {
-            card.open();
-            return Some(card);
-        }
+    pub fn get_deal_number(&self) -> u64 {
+        self.deal_number
+    }
 
-        None
+    // how many more times the waste can be turned over, None when there is no limit
+    pub fn get_passes_left(&self) -> Option<usize> {
+        let max_passes = self.variant.get_max_passes()?;
+        Some(max_passes.saturating_sub(self.board.get_recycles() + 1))
     }
 
-    // get methods
+    pub fn get_waste_len(&self) -> usize {
+        self.board.count(PileKind::Waste)
+    }
 
-    fn get_len_from_tableau(&self, tableau_number: usize) -> Option<usize> {
-        match tableau_number {
-            1 => Some(self.tableau_1.len()),
-            2 => Some(self.tableau_2.len()),
-            3 => Some(self.tableau_3.len()),
-            4 => Some(self.tableau_4.len()),
-            5 => Some(self.tableau_5.len()),
-            6 => Some(self.tableau_6.len()),
-            7 => Some(self.tableau_7.len()),
-            _ => None
-        }
+    pub fn get_move_counts(&self) -> &MoveCounts {
+        &self.move_counts
     }
 
-    fn get_from_tableau(&self, tableau_number: usize, i: usize) -> Option<&Card> {
-        match tableau_number {
-            1 => self.tableau_1.get(i),
-            2 => self.tableau_2.get(i),
-            3 => self.tableau_3.get(i),
-            4 => self.tableau_4.get(i),
-            5 => self.tableau_5.get(i),
-            6 => self.tableau_6.get(i),
-            7 => self.tableau_7.get(i),
-            _ => None
-        }
+    pub fn get_elapsed(&self) -> Duration {
+        self.timer.elapsed()
     }
 
-    fn get_deal_size(&self) -> usize {
-        match self.deal_size {
-            DealSize::One => 1,
-            DealSize::Three => 3,
-        }
+    // the clock only runs while the game is being played
+    pub fn resume_timer(&mut self) {
+        self.timer.resume();
     }
 
-    // move methods
+    pub fn pause_timer(&mut self) {
+        self.timer.pause();
+    }
 
-    fn move_to_foundation(&mut self, foundation_suit: Suit, card: Card) -> Option<Card> {
-        match foundation_suit {
-            Suit::Clubs => self.clubs_foundation.push(card),
-            Suit::Diamonds => self.diamonds_foundation.push(card),
-            Suit::Hearts => self.hearts_foundation.push(card),
-            Suit::Spades => self.spades_foundation.push(card),
+    pub fn get_scoring(&self) -> Scoring {
+        self.scoring
+    }
+
+    pub fn get_time_bonus(&self) -> i32 {
+        if self.is_won() {
+            self.scoring.time_bonus(self.get_elapsed().as_secs())
+        } else {
+            0
         }
     }
 
-    fn move_to_tableau(&mut self, tableau_number: usize, card: Card) -> Option<Card> {
-        match tableau_number {
-            1 => self.tableau_1.push(card),
-            2 => self.tableau_2.push(card),
-            3 => self.tableau_3.push(card),
-            4 => self.tableau_4.push(card),
-            5 => self.tableau_5.push(card),
-            6 => self.tableau_6.push(card),
-            7 => self.tableau_7.push(card),
-            _ => Some(card)
+    pub fn get_score(&self) -> i32 {
+        self.score + self.get_time_bonus()
+    }
+
+    // Vegas money including this game
+    pub fn get_bankroll(&self) -> i32 {
+        match self.scoring {
+            Scoring::Vegas => self.bankroll + self.score,
+            Scoring::Standard => self.bankroll,
         }
     }
 
-    fn move_to_waste(&mut self, card: Card) -> Option<Card> {
-        self.waste.push(card)
+    pub fn set_bankroll(&mut self, bankroll: i32) {
+        self.bankroll = bankroll;
     }
 
-    fn move_to_stock(&mut self, card: Card) -> Option<Card> {
-        self.stock.push(card)
+    // the foundation building a suit, or the one its Ace would start
+    pub fn foundation_of_suit(&self, suit: Suit) -> Option<PileId> {
+        self.board.foundation_of_suit(suit)
     }
 
     // helper methods
 
-    fn go_back(&mut self) {
-        while let Some(mut card) = self.pop_from_waste() {
-            card.close();
-            self.move_to_stock(card);
+    // the foundation a card can go on right now
+    fn foundation_for(&self, from: PileId, card: &Card) -> Option<PileId> {
+        self.board.ids_of(PileKind::Foundation)
+            .into_iter()
+            .find(|to| *to != from && self.variant.check_put(&self.board, *to, card, 1).is_ok())
+    }
+
+    // whether taking `size` cards off the pile will turn a face-down card up
+    fn will_flip(&self, id: PileId, size: usize) -> bool {
+        let len = self.board.get_len(id);
+        if len <= size {
+            return false;
+        }
+
+        match self.board.get_card(id, len - size - 1) {
+            Some(card) => !card.is_open(),
+            None => false,
         }
     }
 
-    fn can_push_to_tableau(&self, tableau_number: usize, card: &Card) -> bool {
-        match tableau_number {
-            1 => self.tableau_1.can_push(card),
-            2 => self.tableau_2.can_push(card),
-            3 => self.tableau_3.can_push(card),
-            4 => self.tableau_4.can_push(card),
-            5 => self.tableau_5.can_push(card),
-            6 => self.tableau_6.can_push(card),
-            7 => self.tableau_7.can_push(card),
-            _ => false
+    fn perform(&mut self, mv: Move) -> Result<Record, MoveError> {
+        let mut ops = match mv {
+            Move::Deal => self.variant.deal_ops(&self.board)?,
+            Move::Transfer(from, to, size) => {
+                self.variant.check_move(&self.board, from, to, size)?;
+                self.variant.move_ops(&self.board, from, to, size)
+            },
+        };
+        for op in ops.iter() {
+            self.board.apply(op);
         }
+
+        let mut flipped = false;
+        // a move to somewhere else that still takes cards out, like a pair in Pyramid
+        let mut collected = match mv {
+            Move::Transfer(_, to, _) if to.kind != PileKind::Foundation => ops.iter()
+                .map(|op| match op {
+                    Op::Move { to, count, .. } if to.kind == PileKind::Foundation => *count,
+                    _ => 0,
+                })
+                .sum(),
+            _ => 0,
+        };
+        loop {
+            let after = self.variant.after_move(&self.board);
+            if after.is_empty() {
+                break;
+            }
+            for op in after {
+                match op {
+                    Op::Flip(_) => flipped = true,
+                    Op::Move { to, count, .. } if to.kind == PileKind::Foundation => collected += count,
+                    _ => (),
+                }
+                self.board.apply(&op);
+                ops.push(op);
+            }
+        }
+
+        let recycled = ops.iter().any(|op| matches!(op, Op::Recycle));
+        let mut record = Record { mv, ops, flipped, collected, recycled, points: 0 };
+        self.score_move(&mut record);
+        Ok(record)
     }
 
-     // public methods
+    fn score_move(&mut self, record: &mut Record) {
+        record.points = self.scoring.points(record, self.get_deal_size() == 1, self.variant.has_redeal_penalty(), self.score);
+        self.score += record.points;
+    }
 
-    pub fn deal(&mut self) {
+    fn revert(&mut self, record: &Record) {
+        self.score -= record.points;
 
-        if self.stock.len() == 0 {
-            self.go_back();
-            return;
+        for op in record.ops.iter().rev() {
+            self.board.revert(op);
         }
+    }
 
-        for _ in 0..self.get_deal_size() {
-            if let Some(card) = self.pop_from_stock() {
-                self.move_to_waste(card);
+    // every card that can reach the top of the waste by dealing, without making any other move
+    fn reachable_stock_cards(&self) -> Vec<&Card> {
+        let mut stock: Vec<&Card> = self.board.ids_of(PileKind::Stock).into_iter().flat_map(|id| self.board.get_cards(id)).collect();
+        let mut waste: Vec<&Card> = self.board.ids_of(PileKind::Waste).into_iter().flat_map(|id| self.board.get_cards(id)).collect();
+        let mut reachable: Vec<&Card> = waste.last().cloned().into_iter().collect();
+        let mut passes_left = self.get_passes_left();
+
+        // two full passes are enough to see every card that will ever show up
+        for _ in 0..2 * (stock.len() + waste.len() + 1) {
+            if stock.is_empty() {
+                if waste.is_empty() || passes_left == Some(0) {
+                    break;
+                }
+                passes_left = passes_left.map(|passes| passes - 1);
+                while let Some(card) = waste.pop() {
+                    stock.push(card);
+                }
+                continue;
+            }
+
+            for _ in 0..self.get_deal_size() {
+                if let Some(card) = stock.pop() {
+                    waste.push(card);
+                }
+            }
+            if let Some(card) = waste.last() {
+                reachable.push(card);
             }
         }
+
+        reachable
     }
 
-    pub fn waste_to_foundation(&mut self, foundation_suit: Suit) -> bool {
-        if let Some(waste_card) = self.pop_from_waste() {
-            if let Some(card) = self.move_to_foundation(foundation_suit, waste_card) {
-                self.move_to_waste(card);
-                return false;
-            }
+    fn can_place(&self, card: &Card) -> bool {
+        let mut card = card.clone();
+        card.open();
 
-            return true;
+        self.board.get_ids()
+            .iter()
+            .filter(|id| matches!(id.kind, PileKind::Foundation | PileKind::Tableau | PileKind::Slot))
+            .any(|id| self.variant.check_put(&self.board, *id, &card, 1).is_ok())
+    }
+
+    // whether a move changes anything worth changing, as opposed to cycling the stock,
+    // taking cards back off the foundations or moving a whole column to an empty one
+    fn is_progress(&self, mv: &Move) -> bool {
+        match *mv {
+            Move::Deal => self.deal_is_a_move(),
+            Move::Transfer(from, _, _) if from.kind == PileKind::Foundation => false,
+            Move::Transfer(from, to, size) if from.kind == PileKind::Tableau && to.kind == PileKind::Tableau => {
+                let len = self.board.get_len(from);
+                // a whole column moved to an empty one gains nothing
+                len > 0 && (size < len || self.board.get_len(to) > 0)
+            },
+            _ => true,
         }
+    }
 
-        false
+     // public methods
+
+    // whether a deal puts cards into play, on the tableau, a foundation or in hand,
+    // rather than turning them onto a waste to be picked from
+    pub fn deal_is_a_move(&self) -> bool {
+        self.board.first_of(PileKind::Waste).is_none()
     }
 
-    pub fn waste_to_tableau(&mut self, tableau_number: usize) -> bool {
-        if let Some(waste_card) = self.pop_from_waste() {
-            if let Some(card) = self.move_to_tableau(tableau_number, waste_card) {
-                self.move_to_waste(card);
-                return false;
-            }
+    // whether the layout is made of overlapping cards, like Pyramid's, which have no numbers
+    pub fn has_slots(&self) -> bool {
+        self.board.first_of(PileKind::Slot).is_some()
+    }
+
+    pub fn is_won(&self) -> bool {
+        self.variant.is_won(&self.board)
+    }
 
-            return true;
+    // true when nothing but cycling the stock or taking cards back off the
+    // foundations is left to do
+    pub fn is_stuck(&self) -> bool {
+        if self.is_won() {
+            return false;
+        }
+
+        if self.legal_moves().iter().any(|mv| self.is_progress(mv)) {
+            return false;
         }
 
-        false
+        !self.reachable_stock_cards().iter().any(|card| self.can_place(card))
     }
 
-    pub fn tableau_to_foundation(&mut self, tableau_number: usize, foundation_suit: Suit) -> bool {
-        if let Some(tableau_card) = self.pop_from_tableau(tableau_number) {
-            if let Some(card) = self.move_to_foundation(foundation_suit, tableau_card) {
-                self.move_to_tableau(tableau_number, card);
-                return false;
-            }
-            return true;
+    // the legal moves of the card on top of the waste
+    pub fn waste_moves(&self) -> Vec<Move> {
+        match self.board.first_of(PileKind::Waste) {
+            Some(waste) => variant::moves_from(self.variant.as_ref(), &self.board, waste),
+            None => Vec::new(),
         }
+    }
+
+    // every move that can be played right now
+    pub fn legal_moves(&self) -> Vec<Move> {
+        self.variant.legal_moves(&self.board)
+    }
 
-        false
+    // moves to the foundations that can never be a mistake
+    pub fn safe_foundation_moves(&self) -> Vec<Move> {
+        self.legal_moves()
+            .into_iter()
+            .filter(|mv| match *mv {
+                Move::Transfer(from, to, 1) if to.kind == PileKind::Foundation && from.kind != PileKind::Foundation => {
+                    self.board.last(from).is_some_and(|card| self.variant.is_safe_on_foundation(&self.board, card))
+                },
+                _ => false,
+            })
+            .collect()
     }
 
-    pub fn tableau_to_tableau(&mut self, from_tableau_number: usize, to_tableau_number: usize, size: usize) -> bool {
-        let i: isize;
-        if let Some(tableau_size) = self.get_len_from_tableau(from_tableau_number) {
-            if tableau_size < size {
-                return false;
+    // plays safe foundation moves one at a time until none are left
+    pub fn auto_play(&mut self) -> Vec<Move> {
+        let mut played = Vec::new();
+
+        while let Some(mv) = self.safe_foundation_moves().first().copied() {
+            if self.play(mv).is_err() {
+                break;
             }
+            played.push(mv);
+        }
 
-            i = (tableau_size as isize) - (size as isize);
-        } else {
-            return false;
+        played
+    }
+
+    // identifies the position regardless of the order of the tableau columns
+    pub fn position_key(&self) -> Vec<u8> {
+        let mut key = self.board.position_key(self.variant.has_ordered_columns());
+        // the same cards with fewer passes left through the stock is a different position
+        if self.variant.get_max_passes().is_some() {
+            key.push(self.board.get_recycles() as u8);
         }
+        key
+    }
 
-        if let Some(first_card) = self.get_from_tableau(from_tableau_number, i as usize) {
-            if !self.can_push_to_tableau(to_tableau_number, &first_card) {
-                return false;
-            }
+    // a fresh game with the same deal
+    pub fn restart(&self) -> Game {
+        let mut game = Game::new(self.variant.clone(), self.deal_number, self.scoring);
+        game.set_bankroll(self.get_bankroll());
+        game
+    }
 
-            let mut tmp_stack: Vec<Card> = Vec::with_capacity(size);
+    pub fn new_deal(&self) -> Game {
+        let mut game = Game::new(self.variant.clone(), random_deal_number(self.variant.get_max_deal_number()), self.scoring);
+        game.set_bankroll(self.get_bankroll());
+        game
+    }
 
-            for _ in 0..size {
-                tmp_stack.push(self.pop_from_tableau(from_tableau_number).unwrap())
-            }
+    pub fn set_hint(&mut self, hint: Option<Move>) {
+        self.hint = hint;
+    }
 
-            while let Some(card) = tmp_stack.pop() {
-                if let Some(card_2) = self.move_to_tableau(to_tableau_number, card) {
-                    self.move_to_tableau(from_tableau_number, card_2).unwrap();
-                }
+    pub fn play(&mut self, mv: Move) -> Result<(), MoveError> {
+        let record = self.perform(mv)?;
+        self.move_counts.count(&record);
+        self.hint = None;
+        self.history.push(record);
+        Ok(())
+    }
+
+    pub fn undo(&mut self) -> Option<Move> {
+        let record = self.history.pop()?;
+        self.revert(&record);
+        self.move_counts.undos += 1;
+        self.hint = None;
+        Some(record.mv)
+    }
+
+    pub fn redo(&mut self) -> Option<Move> {
+        let mv = self.history.last_undone()?;
+        // not counted again, the move was counted when it was first played
+        let record = self.perform(mv).ok()?;
+        self.history.push_redone(record);
+        self.hint = None;
+        Some(mv)
+    }
+
+    pub fn deal(&mut self) -> Result<(), MoveError> {
+        self.play(Move::Deal)
+    }
+
+    pub fn transfer(&mut self, from: PileId, to: PileId, size: usize) -> Result<(), MoveError> {
+        self.play(Move::Transfer(from, to, size))
+    }
+
+    // where a card sent to "any foundation" goes, or whichever explains best why it cannot
+    fn foundation_to_try(&self, from: PileId, card: &Card) -> Option<PileId> {
+        self.foundation_for(from, card)
+            .or_else(|| self.board.foundation_of_suit(card.get_suit()))
+            .or_else(|| self.board.first_of(PileKind::Foundation))
+    }
+
+    // moves the top card of a pile to the first pile of a kind that takes it, returning that pile
+    pub fn transfer_to_any(&mut self, from: PileId, kind: PileKind) -> Result<PileId, MoveError> {
+        let card = self.board.get_pile(from).ok_or(MoveError::InvalidPile(from))?.last().ok_or(MoveError::EmptyPile(from))?;
+        let to = match kind {
+            PileKind::Foundation => self.foundation_to_try(from, card),
+            // the first one otherwise, to tell why none of them will do
+            _ => self.board.ids_of(kind)
+                .into_iter()
+                .find(|to| self.variant.check_move(&self.board, from, *to, 1).is_ok())
+                .or_else(|| self.board.first_of(kind)),
+        };
+
+        let to = to.ok_or(MoveError::NoPile(kind))?;
+        self.transfer(from, to, 1)?;
+        Ok(to)
+    }
+
+    // the longest run on top of a pile that can go on the other one
+    pub fn fitting_run(&self, from: PileId, to: PileId) -> Result<usize, MoveError> {
+        let len = self.board.get_pile(from).ok_or(MoveError::InvalidPile(from))?.len();
+        self.board.get_pile(to).ok_or(MoveError::InvalidPile(to))?;
+        if from == to {
+            return Err(MoveError::SamePile(from));
+        }
+
+        if len == 0 {
+            return Err(MoveError::EmptyPile(from));
+        }
+
+        // the runs that failed only because their first card does not fit
+        let mut misfits = Vec::new();
+        for size in (1..=len).rev() {
+            match self.variant.check_move(&self.board, from, to, size) {
+                Ok(()) => return Ok(size),
+                Err(MoveError::FaceDown { .. }) | Err(MoveError::BrokenRun { .. }) | Err(MoveError::SingleCardFrom(_)) | Err(MoveError::SingleCardTo(_)) => continue,
+                Err(error) => misfits.push(error),
             }
+        }
 
-            return true;
+        // a run that fits but is too long to carry over says more than the ones that do not fit,
+        // and so does a single candidate
+        if let Some(i) = misfits.iter().position(|error| matches!(error, MoveError::TooManyCards { .. })) {
+            return Err(misfits.remove(i));
         }
+        match misfits.len() {
+            1 => Err(misfits.remove(0)),
+            _ => Err(MoveError::NoRunFits { from, to }),
+        }
+    }
 
-        false
+    // moves as many cards as fit, returning how many that was
+    pub fn transfer_auto(&mut self, from: PileId, to: PileId) -> Result<usize, MoveError> {
+        let size = self.fitting_run(from, to)?;
+        self.transfer(from, to, size)?;
+        Ok(size)
     }
+}
 
-    pub fn foundation_to_tableau(&mut self, foundation_suit: Suit, tableau_number: usize) -> bool {
-        if let Some(foundation_card) = self.pop_from_foundation(foundation_suit) {
-            if let Some(card) = self.move_to_tableau(tableau_number, foundation_card) {
-                self.move_to_foundation(foundation_suit, card);
-                return false;
+impl Game {
+    // where a face-up card that can be picked up or built on is
+    pub fn find_card(&self, rank: &Rank, suit: Suit) -> Option<Spot> {
+        let is_card = |card: &Card| card.is_open() && card.get_rank() == *rank && card.get_suit() == suit;
+
+        for id in self.board.get_ids().iter().copied() {
+            let cards = self.board.get_cards(id);
+            let found = match id.kind {
+                PileKind::Stock => None,
+                // any face-up tableau card can be picked up with the cards on it
+                PileKind::Tableau => cards.iter().position(is_card),
+                _ => cards.len().checked_sub(1).filter(|i| is_card(&cards[*i])),
+            };
+
+            if let Some(index) = found {
+                return Some(Spot { pile: id, index });
             }
-            return true;
         }
 
-        false
+        None
+    }
+
+    fn is_hinted(&self, spot: Spot) -> bool {
+        match self.hint {
+            Some(Move::Deal) => spot.pile.kind == PileKind::Stock,
+            Some(Move::Transfer(from, to, size)) => {
+                if spot.pile == from {
+                    let len = self.board.get_len(from);
+                    spot.index + size >= len && spot.index < len
+                } else {
+                    spot.pile == to && spot.index == self.board.get_len(to).saturating_sub(1)
+                }
+            },
+            None => false,
+        }
     }
 }
 
 impl fmt::Display for Game {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        // clubs
-        write!(f, " ").unwrap();
-        if let Some(last_club) = self.clubs_foundation.last() {
-            last_club.fmt(f).unwrap();
-        } else {
-            write!(f, "{}", '\u{2663}').unwrap();
-        }
-        write!(f, " ").unwrap();
+        self.variant.render(&self.board, &|spot| self.is_hinted(spot), f)
+    }
+}
 
-        // diamonds
-        write!(f, " ").unwrap();
-        if let Some(last_diamond) = self.diamonds_foundation.last() {
-            last_diamond.fmt(f).unwrap();
-        } else {
-            write!(f, "{}", '\u{2666}').unwrap();
-        }
-        write!(f, " ").unwrap();
+#[cfg(test)]
+pub mod tests {
+    use super::*;
 
-        // hearts
-        write!(f, " ").unwrap();
-        if let Some(last_heart) = self.hearts_foundation.last() {
-            last_heart.fmt(f).unwrap();
-        } else {
-            write!(f, "{}", '\u{2665}').unwrap();
-        }
-        write!(f, " ").unwrap();
+    // every card on the board, pile by pile, and whether it is face-up
+    fn cards(game: &Game) -> Vec<Vec<(String, bool)>> {
+        game.board.get_ids()
+            .iter()
+            .map(|id| game.board.get_cards(*id).iter().map(|card| (card.get_code(), card.is_open())).collect())
+            .collect()
+    }
 
-        // spades
-        write!(f, " ").unwrap();
-        if let Some(last_spade) = self.spades_foundation.last() {
-            last_spade.fmt(f).unwrap();
-        } else {
-            write!(f, "{}", '\u{2660}').unwrap();
+    const RANK_CODES: [&str; 13] = ["A", "2", "3", "4", "5", "6", "7", "8", "9", "10", "J", "Q", "K"];
+
+    // the cards of a suit from the Ace up to `top`, like "AC 2C 3C"
+    pub fn suit_up_to(suit: &str, top: usize) -> String {
+        RANK_CODES[..top].iter().map(|rank| format!("{}{}", rank, suit)).collect::<Vec<String>>().join(" ")
+    }
+
+    // a game with the piles given as save lines, any pile left out is empty
+    pub fn position(variant: Box<dyn Variant>, piles: &[String]) -> Game {
+        let mut text: String = Game::new(variant, 1, Scoring::Standard)
+            .to_save()
+            .lines()
+            .take_while(|line| line.split_whitespace().next().and_then(PileKind::from_name).is_none())
+            .map(|line| format!("{}\n", line))
+            .collect();
+        for pile in piles {
+            text.push_str(pile);
+            text.push('\n');
         }
-        write!(f, " ").unwrap();
 
-        // waste
-        for i in (0..3).rev() {
-            write!(f, " ").unwrap();
-            if self.waste.len() <= i {
-                write!(f, " ").unwrap();
-                continue;
-            }
-            if let Some(waste_card) = self.waste.get(self.waste.len() - i - 1) {
-                waste_card.fmt(f).unwrap();
-            } else {
-                write!(f, " ").unwrap();
+        Game::from_save(&text).unwrap()
+    }
+
+    // the cards of the variant's deck that none of the piles given hold, face-down, as save codes
+    pub fn rest_of_deck(variant: Box<dyn Variant>, piles: &[String]) -> String {
+        let game = Game::new(variant, 1, Scoring::Standard);
+        let mut rest: Vec<String> = game.board.get_ids()
+            .iter()
+            .flat_map(|id| game.board.get_cards(*id).iter().map(|card| card.get_code()))
+            .collect();
+        let used = piles.iter()
+            .flat_map(|line| line.split_whitespace())
+            .map(|code| code.trim_start_matches('~'))
+            .filter(|code| Card::from_code(code).is_some());
+        for code in used {
+            if let Some(index) = rest.iter().position(|card| card == code) {
+                rest.remove(index);
             }
         }
 
-        write!(f, " ").unwrap();
+        rest.iter().map(|code| format!("~{}", code)).collect::<Vec<String>>().join(" ")
+    }
 
-        // stock
-        if let Some(stock_card) = self.stock.last() {
-            stock_card.fmt(f).unwrap();
-        } else {
-            write!(f, "{}", '\u{1F0EA}').unwrap();
-        }
+    // a game of Klondike with the piles given as save lines, any pile left out is empty
+    pub fn klondike_position(rules: KlondikeRules, recycles: usize, piles: &[String]) -> Game {
+        let mut game = position(Box::new(Klondike::new(rules)), piles);
+        game.board.set_recycles(recycles);
+        game
+    }
 
-        write!(f, " \n\n").unwrap();
+    // every card up but the 10 of diamonds, which only a pass through the stock can bring back
+    pub fn waiting_on_the_waste(pass_limit: PassLimit, recycles: usize) -> Game {
+        let rules = KlondikeRules { pass_limit, ..KlondikeRules::default() };
+        klondike_position(rules, recycles, &[
+            String::from("waste 10D QS"),
+            format!("foundation 1 {}", suit_up_to("C", 10)),
+            format!("foundation 2 {}", suit_up_to("D", 9)),
+            format!("foundation 3 {}", suit_up_to("H", 10)),
+            format!("foundation 4 {}", suit_up_to("S", 10)),
+            String::from("tableau 1 ~JC ~JD ~JH ~JS ~KD ~KH ~QD ~QH QC"),
+            String::from("tableau 2 KC"),
+            String::from("tableau 3 KS"),
+        ])
+    }
 
-        write!(f, " 1  2  3  4  5  6  7\n").unwrap();
+    #[test]
+    fn a_full_set_of_foundations_is_won() {
+        let piles: Vec<String> = ["C", "D", "H", "S"].iter()
+            .enumerate()
+            .map(|(i, suit)| format!("foundation {} {}", i + 1, suit_up_to(suit, 13)))
+            .collect();
+        let game = klondike_position(KlondikeRules::default(), 0, &piles);
+
+        assert!(game.is_won());
+        assert!(!game.is_stuck());
+        assert!(!waiting_on_the_waste(PassLimit::Unlimited, 0).is_won());
+    }
 
-        // tableaus
-        for i in 0..14 {
-            if self.tableau_1.len() < i && self.tableau_2.len() < i && self.tableau_3.len() < i && self.tableau_4.len() < i && self.tableau_5.len() < i && self.tableau_6.len() < i && self.tableau_7.len() < i {
-                break;
-            }
-            write!(f, " ").unwrap();
-            if let Some(tableau_card) = self.tableau_1.get(i) {
-                tableau_card.fmt(f).unwrap();
-            } else {
-                write!(f, " ").unwrap();
-            }
-            write!(f, " ").unwrap();
+    #[test]
+    fn stuck_once_the_stock_cannot_bring_up_a_card_that_fits() {
+        // the 10 of diamonds comes back with the next pass, if there is one
+        assert!(!waiting_on_the_waste(PassLimit::Unlimited, 5).is_stuck());
+        assert!(!waiting_on_the_waste(PassLimit::Three, 1).is_stuck());
+        assert!(waiting_on_the_waste(PassLimit::Three, 2).is_stuck());
+        assert!(waiting_on_the_waste(PassLimit::Single, 0).is_stuck());
+    }
 
-            write!(f, " ").unwrap();
-            if let Some(tableau_card) = self.tableau_2.get(i) {
-                tableau_card.fmt(f).unwrap();
-            } else {
-                write!(f, " ").unwrap();
-            }
-            write!(f, " ").unwrap();
+    // a few cards left with a move of every kind to make
+    pub fn nearly_done() -> Game {
+        klondike_position(KlondikeRules::default(), 0, &[
+            String::from("stock ~KS"),
+            String::from("waste 9H"),
+            format!("foundation 1 {}", suit_up_to("C", 12)),
+            format!("foundation 2 {}", suit_up_to("D", 12)),
+            format!("foundation 3 {}", suit_up_to("H", 8)),
+            format!("foundation 4 {}", suit_up_to("S", 9)),
+            String::from("tableau 1 ~KC ~10H ~JH ~QH KD"),
+            String::from("tableau 2 ~KH ~JS ~QS 10S"),
+        ])
+    }
 
-            write!(f, " ").unwrap();
-            if let Some(tableau_card) = self.tableau_3.get(i) {
-                tableau_card.fmt(f).unwrap();
-            } else {
-                write!(f, " ").unwrap();
-            }
-            write!(f, " ").unwrap();
+    fn pile(kind: PileKind, number: usize) -> PileId {
+        PileId::new(kind, number)
+    }
 
-            write!(f, " ").unwrap();
-            if let Some(tableau_card) = self.tableau_4.get(i) {
-                tableau_card.fmt(f).unwrap();
-            } else {
-                write!(f, " ").unwrap();
-            }
-            write!(f, " ").unwrap();
+    #[test]
+    fn legal_moves_cover_the_stock_the_waste_and_the_foundations() {
+        let game = nearly_done();
+        let moves: Vec<String> = game.legal_moves().iter().map(|mv| mv.to_string()).collect();
+
+        // a King goes to the first empty column only, the others would be the same move
+        assert_eq!(moves, [
+            "deal",
+            "waste to foundation 3",
+            "waste to tableau 2",
+            "tableau 1 to foundation 2",
+            "tableau 1 to tableau 3",
+            "tableau 2 to foundation 4",
+            "foundation 1 to tableau 1",
+        ]);
+    }
 
-            write!(f, " ").unwrap();
-            if let Some(tableau_card) = self.tableau_5.get(i) {
-                tableau_card.fmt(f).unwrap();
-            } else {
-                write!(f, " ").unwrap();
-            }
-            write!(f, " ").unwrap();
+    #[test]
+    fn standard_scoring_counts_moves_and_never_goes_below_zero() {
+        let mut game = nearly_done();
+        let queen_back = Move::Transfer(pile(PileKind::Foundation, 1), pile(PileKind::Tableau, 1), 1);
+
+        // taking a card back off a foundation costs 15, but there is nothing to take it from
+        assert!(game.play(queen_back).is_ok());
+        assert_eq!(game.get_score(), 0);
+        game.undo();
+
+        // 10 for the foundation and 5 for the card it turns up
+        assert!(game.transfer(pile(PileKind::Tableau, 2), pile(PileKind::Foundation, 4), 1).is_ok());
+        assert_eq!(game.get_score(), 15);
+        assert!(game.transfer(pile(PileKind::Waste, 1), pile(PileKind::Foundation, 3), 1).is_ok());
+        assert_eq!(game.get_score(), 25);
+        assert!(game.play(queen_back).is_ok());
+        assert_eq!(game.get_score(), 10);
+
+        game.undo();
+        assert_eq!(game.get_score(), 25);
+        game.undo();
+        game.undo();
+        assert_eq!(game.get_score(), 0);
+    }
 
-            write!(f, " ").unwrap();
-            if let Some(tableau_card) = self.tableau_6.get(i) {
-                tableau_card.fmt(f).unwrap();
-            } else {
-                write!(f, " ").unwrap();
-            }
-            write!(f, " ").unwrap();
+    #[test]
+    fn vegas_scoring_pays_for_foundation_cards_out_of_the_bankroll() {
+        let mut game = nearly_done();
+        game.scoring = Scoring::Vegas;
+        game.score = Scoring::Vegas.initial_score();
+        game.set_bankroll(100);
+        assert_eq!(game.get_score(), -52);
+        assert_eq!(game.get_bankroll(), 48);
+
+        // turning a card up is worth nothing in Vegas
+        assert!(game.transfer(pile(PileKind::Tableau, 2), pile(PileKind::Foundation, 4), 1).is_ok());
+        assert_eq!(game.get_score(), -47);
+        assert_eq!(game.get_bankroll(), 53);
+        assert!(game.transfer(pile(PileKind::Foundation, 1), pile(PileKind::Tableau, 1), 1).is_ok());
+        assert_eq!(game.get_score(), -52);
+
+        game.undo();
+        game.undo();
+        assert_eq!(game.get_score(), -52);
+        assert_eq!(game.get_bankroll(), 48);
+    }
+
+    // two runs on the columns, the one on the fourth broken by a card of the wrong color
+    fn runs() -> Game {
+        let columns = [
+            "tableau 1 9S 8H 7C 6H 5C",
+            "tableau 2 10D",
+            "tableau 3 8D",
+            "tableau 4 JC 10H 9H",
+            "tableau 5 QD",
+        ];
+
+        // everything else waits in the stock
+        let mut piles: Vec<String> = columns.iter().map(|line| line.to_string()).collect();
+        piles.push(format!("stock {}", rest_of_deck(Box::new(Klondike::new(KlondikeRules::default())), &piles)));
+        klondike_position(KlondikeRules::default(), 0, &piles)
+    }
+
+    #[test]
+    fn the_longest_run_that_fits_is_moved() {
+        let mut game = runs();
+        let column = |number| pile(PileKind::Tableau, number);
+
+        // only the 7 of clubs and what is on it go on the 8 of diamonds
+        assert_eq!(game.fitting_run(column(1), column(3)).ok(), Some(3));
+        assert_eq!(game.fitting_run(column(1), column(2)).ok(), Some(5));
+
+        assert_eq!(game.transfer_auto(column(1), column(3)).ok(), Some(3));
+        assert_eq!(game.get_pile_len(column(1)), 2);
+        assert_eq!(game.get_pile_len(column(3)), 4);
+    }
+
+    #[test]
+    fn a_broken_run_does_not_move() {
+        let mut game = runs();
+        let column = |number| pile(PileKind::Tableau, number);
 
-            write!(f, " ").unwrap();
-            if let Some(tableau_card) = self.tableau_7.get(i) {
-                tableau_card.fmt(f).unwrap();
-            } else {
-                write!(f, " ").unwrap();
+        assert!(matches!(game.transfer(column(4), column(5), 3), Err(MoveError::BrokenRun { .. })));
+        assert!(matches!(game.transfer(column(4), column(5), 2), Err(MoveError::BrokenRun { .. })));
+        assert_eq!(game.get_pile_len(column(4)), 3);
+        assert!(game.fitting_run(column(4), column(5)).is_err());
+    }
+
+    #[test]
+    fn undo_restores_the_board() {
+        let mut game = Game::new(Box::new(Klondike::new(KlondikeRules::default())), 5, Scoring::Standard);
+        let start = cards(&game);
+
+        let mut played = 0;
+        for i in 0..40 {
+            let moves = game.legal_moves();
+            if moves.is_empty() {
+                break;
             }
-            write!(f, " ").unwrap();
-            write!(f, "\n").unwrap();
+            assert!(game.play(moves[i % moves.len()]).is_ok());
+            played += 1;
+        }
+        for _ in 0..played {
+            assert!(game.undo().is_some());
         }
-        write!(f, "\n")
+
+        assert!(played > 0);
+        assert_eq!(cards(&game), start);
+        assert_eq!(game.get_score(), 0);
+        assert_eq!(game.board.get_recycles(), 0);
+    }
+
+    #[test]
+    fn redo_plays_the_undone_moves_again() {
+        let mut game = Game::new(Box::new(Klondike::new(KlondikeRules::default())), 5, Scoring::Standard);
+
+        for i in 0..20 {
+            let moves = game.legal_moves();
+            assert!(game.play(moves[i % moves.len()]).is_ok());
+        }
+        let end = cards(&game);
+        let score = game.get_score();
+        let total = game.get_move_counts().total();
+
+        for _ in 0..20 {
+            assert!(game.undo().is_some());
+        }
+        for _ in 0..20 {
+            assert!(game.redo().is_some());
+        }
+
+        assert!(game.redo().is_none());
+        assert_eq!(cards(&game), end);
+        assert_eq!(game.get_score(), score);
+        assert_eq!(game.get_move_counts().total(), total);
     }
-}
\ No newline at end of file
+}
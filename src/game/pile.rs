@@ -0,0 +1,138 @@
+use std::fmt;
+use crate::deck::Card;
+use crate::game::MoveError;
+
+// what a pile is for, which decides how cards come and go
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PileKind {
+    Stock,
+    Waste,
+    Foundation,
+    Tableau,
+    Cell,
+    Reserve,
+    // a single card of a layout where cards overlap, like the pyramid of Pyramid
+    Slot,
+}
+
+impl PileKind {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            PileKind::Stock => "stock",
+            PileKind::Waste => "waste",
+            PileKind::Foundation => "foundation",
+            PileKind::Tableau => "tableau",
+            PileKind::Cell => "cell",
+            PileKind::Reserve => "reserve",
+            PileKind::Slot => "slot",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<PileKind> {
+        match name {
+            "stock" => Some(PileKind::Stock),
+            "waste" => Some(PileKind::Waste),
+            "foundation" => Some(PileKind::Foundation),
+            "tableau" => Some(PileKind::Tableau),
+            "cell" => Some(PileKind::Cell),
+            "reserve" => Some(PileKind::Reserve),
+            "slot" => Some(PileKind::Slot),
+            _ => None,
+        }
+    }
+
+    // there is only ever one of these on a board, so they go without a number
+    pub fn is_single(&self) -> bool {
+        matches!(self, PileKind::Stock | PileKind::Waste | PileKind::Reserve)
+    }
+}
+
+// a pile on the board, numbered from 1 among the piles of its kind
+#[derive(Clone, Copy, PartialEq)]
+pub struct PileId {
+    pub kind: PileKind,
+    pub number: usize,
+}
+
+impl PileId {
+    pub fn new(kind: PileKind, number: usize) -> PileId {
+        PileId {
+            kind,
+            number,
+        }
+    }
+}
+
+impl fmt::Display for PileId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.kind.is_single() {
+            write!(f, "{}", self.kind.get_name())
+        } else {
+            write!(f, "{} {}", self.kind.get_name(), self.number)
+        }
+    }
+}
+
+pub trait Pile {
+    fn get_kind(&self) -> PileKind;
+    fn get_cards(&self) -> &Vec<Card>;
+    fn get_cards_mut(&mut self) -> &mut Vec<Card>;
+    fn clone_box(&self) -> Box<dyn Pile>;
+
+    // why a card cannot be put on the pile by the player, checked for the first card of a move
+    fn check_push(&self, card: &Card) -> Result<(), MoveError>;
+
+    // only piles that build runs let several cards come and go at once
+    fn moves_runs(&self) -> bool {
+        false
+    }
+
+    // whether face-up cards taken off the top hang together well enough to move as one
+    fn check_run(&self, _run: &[Card]) -> Result<(), MoveError> {
+        Ok(())
+    }
+
+    fn can_push(&self, card: &Card) -> bool {
+        self.check_push(card).is_ok()
+    }
+
+    fn push(&mut self, card: Card) -> Option<Card> {
+        if self.can_push(&card) {
+            self.place(card);
+            None
+        } else {
+            Some(card)
+        }
+    }
+
+    // puts a card on the pile without checking the rules
+    fn place(&mut self, card: Card) {
+        self.get_cards_mut().push(card);
+    }
+
+    fn pop(&mut self) -> Option<Card> {
+        self.get_cards_mut().pop()
+    }
+
+    fn len(&self) -> usize {
+        self.get_cards().len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.get_cards().is_empty()
+    }
+
+    fn last(&self) -> Option<&Card> {
+        self.get_cards().last()
+    }
+
+    fn get(&self, i: usize) -> Option<&Card> {
+        self.get_cards().get(i)
+    }
+}
+
+impl Clone for Box<dyn Pile> {
+    fn clone(&self) -> Box<dyn Pile> {
+        self.clone_box()
+    }
+}
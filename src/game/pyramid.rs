@@ -0,0 +1,176 @@
+use std::fmt;
+use crate::deck::{Card, Deck, Rank};
+use crate::game::board::{Board, Op};
+use crate::game::discard::Discard;
+use crate::game::layout::Layout;
+use crate::game::render;
+use crate::game::slot::Slot;
+use crate::game::stock::Stock;
+use crate::game::variant::{self, Variant};
+use crate::game::waste::Waste;
+use crate::game::{Move, MoveError, PileId, PileKind, Spot};
+
+const ROWS: usize = 7;
+const MAX_PASSES: usize = 3;
+// the ranks of a pair add up to this, Jacks counting 11, Queens 12 and Kings going out alone
+const PAIR_TOTAL: usize = 13;
+
+const STOCK: PileId = PileId { kind: PileKind::Stock, number: 1 };
+const WASTE: PileId = PileId { kind: PileKind::Waste, number: 1 };
+const FOUNDATION: PileId = PileId { kind: PileKind::Foundation, number: 1 };
+
+// 28 cards in a pyramid, taken out in pairs adding up to 13 once nothing lies on them
+#[derive(Clone)]
+pub struct Pyramid {
+    layout: Layout,
+}
+
+impl Pyramid {
+    pub fn new() -> Pyramid {
+        Pyramid {
+            layout: Layout::pyramid(ROWS),
+        }
+    }
+}
+
+impl Variant for Pyramid {
+    fn get_name(&self) -> &'static str {
+        "pyramid"
+    }
+
+    fn clone_box(&self) -> Box<dyn Variant> {
+        Box::new(self.clone())
+    }
+
+    fn get_title(&self) -> String {
+        String::from("Pyramid")
+    }
+
+    fn deal(&self, deal_number: u64) -> Board {
+        let mut deck = Deck::from_seed(deal_number);
+        let slots: Vec<Slot> = (0..self.layout.len()).map(|_| Slot::new(deck.deal(true).unwrap())).collect();
+
+        let mut board = Board::new();
+        board.add(Box::new(Stock::new(deck.cards)));
+        board.add(Box::new(Waste::new()));
+        board.add(Box::new(Discard::new()));
+        for slot in slots {
+            board.add(Box::new(slot));
+        }
+
+        board
+    }
+
+    fn render(&self, board: &Board, hinted: &dyn Fn(Spot) -> bool, f: &mut fmt::Formatter) -> fmt::Result {
+        self.layout.write(f, board, hinted)?;
+
+        render::write_top(f, board, FOUNDATION, '\u{00B7}', hinted)?;
+        write!(f, "  ")?;
+        render::write_top(f, board, WASTE, ' ', hinted)?;
+        render::write_top(f, board, STOCK, '\u{1F0EA}', hinted)?;
+        write!(f, "\n\n")
+    }
+
+    fn deal_ops(&self, board: &Board) -> Result<Vec<Op>, MoveError> {
+        variant::deal_to_waste(self, board, STOCK, WASTE)
+    }
+
+    fn check_take(&self, board: &Board, from: PileId, size: usize) -> Result<(), MoveError> {
+        if from.kind == PileKind::Foundation {
+            return Err(MoveError::CannotMoveFrom(from));
+        }
+
+        variant::check_pile_take(board, from, size)?;
+        self.layout.check_uncovered(board, from)
+    }
+
+    // a card goes onto the one it pairs with, or out on its own when it is a King
+    fn check_put(&self, board: &Board, to: PileId, card: &Card, _size: usize) -> Result<(), MoveError> {
+        match to.kind {
+            PileKind::Foundation if card.get_rank() == Rank::King => Ok(()),
+            PileKind::Foundation => Err(MoveError::KingsOnly { card: card.clone() }),
+            PileKind::Slot => {
+                let onto = board.last(to).ok_or(MoveError::CannotMoveTo(to))?;
+                self.layout.check_uncovered(board, to)?;
+                if card.get_numbered_rank() + onto.get_numbered_rank() != PAIR_TOTAL {
+                    return Err(MoveError::NotThirteen { card: card.clone(), onto: onto.clone() });
+                }
+                Ok(())
+            },
+            _ => Err(MoveError::CannotMoveTo(to)),
+        }
+    }
+
+    // both cards of a pair go out
+    fn move_ops(&self, _board: &Board, from: PileId, to: PileId, size: usize) -> Vec<Op> {
+        if to.kind == PileKind::Slot {
+            vec![Op::Move { from, to: FOUNDATION, count: 1 }, Op::Move { from: to, to: FOUNDATION, count: 1 }]
+        } else {
+            vec![Op::Move { from, to, count: size }]
+        }
+    }
+
+    fn legal_moves(&self, board: &Board) -> Vec<Move> {
+        let mut moves = Vec::new();
+
+        if self.deal_ops(board).is_ok() {
+            moves.push(Move::Deal);
+        }
+
+        // two cards of the pyramid pair up the same whichever goes onto the other
+        for from in board.ids_of(PileKind::Waste).into_iter().chain(board.ids_of(PileKind::Slot)) {
+            moves.extend(variant::moves_from(self, board, from)
+                .into_iter()
+                .filter(|mv| !matches!(mv, Move::Transfer(from, to, _) if from.kind == PileKind::Slot && to.kind == PileKind::Slot && from.number > to.number)));
+        }
+
+        moves
+    }
+
+    // the pyramid taken apart, whatever is left in the stock
+    fn is_won(&self, board: &Board) -> bool {
+        board.count(PileKind::Slot) == 0
+    }
+
+    fn is_safe_on_foundation(&self, _board: &Board, _card: &Card) -> bool {
+        false
+    }
+
+    fn get_max_passes(&self) -> Option<usize> {
+        Some(MAX_PASSES)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::tests::{position, rest_of_deck};
+
+    fn slot(number: usize) -> PileId {
+        PileId::new(PileKind::Slot, number)
+    }
+
+    #[test]
+    fn cards_go_out_in_pairs_adding_up_to_thirteen_and_kings_alone() {
+        let mut piles = vec![
+            "slot 22 6H".to_string(),
+            "slot 23 7C".to_string(),
+            "slot 24 5D".to_string(),
+            "slot 25 KS".to_string(),
+            "slot 26 QS".to_string(),
+        ];
+        piles.push(format!("stock {}", rest_of_deck(Box::new(Pyramid::new()), &piles)));
+        let mut game = position(Box::new(Pyramid::new()), &piles);
+
+        assert!(game.play(Move::Transfer(slot(22), slot(24), 1)).is_err());
+        assert!(game.play(Move::Transfer(slot(26), FOUNDATION, 1)).is_err());
+
+        assert!(game.play(Move::Transfer(slot(22), slot(23), 1)).is_ok());
+        assert_eq!(game.get_pile_len(FOUNDATION), 2);
+        assert_eq!(game.get_pile_len(slot(22)), 0);
+        assert_eq!(game.get_pile_len(slot(23)), 0);
+
+        assert!(game.play(Move::Transfer(slot(25), FOUNDATION, 1)).is_ok());
+        assert_eq!(game.get_pile_len(FOUNDATION), 3);
+    }
+}
@@ -0,0 +1,45 @@
+use crate::game::{MoveError, Pile, PileKind};
+use crate::deck::Card;
+
+// builds down regardless of suit, one card at a time
+#[derive(Clone)]
+pub struct RankTableau {
+    stack: Vec<Card>,
+    // an empty column takes any card, or else stays empty for good
+    refills: bool,
+}
+
+impl RankTableau {
+    pub fn new(stack: Vec<Card>, refills: bool) -> RankTableau {
+        RankTableau {
+            stack,
+            refills,
+        }
+    }
+}
+
+impl Pile for RankTableau {
+    fn get_kind(&self) -> PileKind {
+        PileKind::Tableau
+    }
+
+    fn get_cards(&self) -> &Vec<Card> {
+        &self.stack
+    }
+
+    fn get_cards_mut(&mut self) -> &mut Vec<Card> {
+        &mut self.stack
+    }
+
+    fn clone_box(&self) -> Box<dyn Pile> {
+        Box::new(self.clone())
+    }
+
+    fn check_push(&self, card: &Card) -> Result<(), MoveError> {
+        match self.stack.last() {
+            Some(last) if last.get_numbered_rank() != card.get_numbered_rank() + 1 => Err(MoveError::WrongRank { card: card.clone(), onto: last.clone() }),
+            None if !self.refills => Err(MoveError::ColumnStaysEmpty),
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use std::fmt;
+use crate::deck::Card;
+use crate::game::board::Board;
+use crate::game::{PileId, Spot};
+
+pub fn mark_open(hinted: bool) -> &'static str {
+    if hinted { "[" } else { " " }
+}
+
+pub fn mark_close(hinted: bool) -> &'static str {
+    if hinted { "]" } else { " " }
+}
+
+// a card between hint marks, `empty` standing in when there is none
+pub fn write_card(f: &mut fmt::Formatter, card: Option<&Card>, empty: char, hinted: bool) -> fmt::Result {
+    write!(f, "{}", mark_open(hinted))?;
+    match card {
+        Some(card) => write!(f, "{}", card)?,
+        None => write!(f, "{}", empty)?,
+    }
+    write!(f, "{}", mark_close(hinted))
+}
+
+// the top card of a pile, the only one that shows
+pub fn write_top(f: &mut fmt::Formatter, board: &Board, id: PileId, empty: char, hinted: &dyn Fn(Spot) -> bool) -> fmt::Result {
+    let spot = Spot { pile: id, index: board.get_len(id).saturating_sub(1) };
+    write_card(f, board.last(id), empty, hinted(spot))
+}
+
+// piles fanned out downwards side by side, with their numbers on top
+pub fn write_columns(f: &mut fmt::Formatter, board: &Board, columns: &[PileId], hinted: &dyn Fn(Spot) -> bool) -> fmt::Result {
+    let numbers: String = columns.iter().map(|id| format!("{:>2} ", id.number)).collect();
+    writeln!(f, "{}", numbers.trim_end())?;
+
+    let rows = columns.iter().map(|id| board.get_len(*id)).max().unwrap_or(0);
+    for i in 0..=rows {
+        for id in columns.iter() {
+            write_card(f, board.get_card(*id, i), ' ', hinted(Spot { pile: *id, index: i }))?;
+        }
+        writeln!(f)?;
+    }
+
+    writeln!(f)
+}
+
+// the top `fan` cards of the waste side by side, the playable one last, then the stock
+pub fn write_waste(f: &mut fmt::Formatter, board: &Board, stock: PileId, waste: PileId, fan: usize, hinted: &dyn Fn(Spot) -> bool) -> fmt::Result {
+    let len = board.get_len(waste);
+    let waste_hinted = hinted(Spot { pile: waste, index: len.saturating_sub(1) });
+    for i in (0..fan).rev() {
+        if i == 0 {
+            write!(f, "{}", mark_open(waste_hinted))?;
+        } else {
+            write!(f, " ")?;
+        }
+        match len.checked_sub(i + 1).and_then(|j| board.get_card(waste, j)) {
+            Some(card) => write!(f, "{}", card)?,
+            None => write!(f, " ")?,
+        }
+    }
+
+    let stock_hinted = hinted(Spot { pile: stock, index: 0 });
+    if waste_hinted {
+        write!(f, "]")?;
+    } else {
+        write!(f, "{}", mark_open(stock_hinted))?;
+    }
+    match board.last(stock) {
+        Some(card) => write!(f, "{}", card)?,
+        None => write!(f, "\u{1F0EA}")?,
+    }
+    write!(f, "{}", mark_close(stock_hinted))
+}
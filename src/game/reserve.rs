@@ -0,0 +1,38 @@
+use crate::game::{MoveError, Pile, PileId, PileKind};
+use crate::deck::Card;
+
+// cards set aside face-down at the deal and played off the top one at a time
+#[derive(Clone)]
+pub struct Reserve {
+    stack: Vec<Card>,
+}
+
+impl Reserve {
+    pub fn new(stack: Vec<Card>) -> Reserve {
+        Reserve {
+            stack,
+        }
+    }
+}
+
+impl Pile for Reserve {
+    fn get_kind(&self) -> PileKind {
+        PileKind::Reserve
+    }
+
+    fn get_cards(&self) -> &Vec<Card> {
+        &self.stack
+    }
+
+    fn get_cards_mut(&mut self) -> &mut Vec<Card> {
+        &mut self.stack
+    }
+
+    fn clone_box(&self) -> Box<dyn Pile> {
+        Box::new(self.clone())
+    }
+
+    fn check_push(&self, _card: &Card) -> Result<(), MoveError> {
+        Err(MoveError::CannotMoveTo(PileId::new(PileKind::Reserve, 1)))
+    }
+}
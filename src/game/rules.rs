@@ -0,0 +1,80 @@
+use std::fmt;
+
+pub const MIN_DRAW_COUNT: usize = 1;
+pub const MAX_DRAW_COUNT: usize = 5;
+
+// how many times the player may go through the stock
+#[derive(Clone, Copy, PartialEq)]
+pub enum PassLimit {
+    Unlimited,
+    Three,
+    Single,
+}
+
+impl PassLimit {
+    pub fn max_passes(&self) -> Option<usize> {
+        match self {
+            PassLimit::Unlimited => None,
+            PassLimit::Three => Some(3),
+            PassLimit::Single => Some(1),
+        }
+    }
+
+    pub fn next(&self) -> PassLimit {
+        match self {
+            PassLimit::Unlimited => PassLimit::Three,
+            PassLimit::Three => PassLimit::Single,
+            PassLimit::Single => PassLimit::Unlimited,
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<PassLimit> {
+        match name {
+            "unlimited" => Some(PassLimit::Unlimited),
+            "three" => Some(PassLimit::Three),
+            "single" => Some(PassLimit::Single),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for PassLimit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PassLimit::Unlimited => write!(f, "unlimited"),
+            PassLimit::Three => write!(f, "three"),
+            PassLimit::Single => write!(f, "single"),
+        }
+    }
+}
+
+// the house rules a game of Klondike is played with
+#[derive(Clone, Copy, PartialEq)]
+pub struct KlondikeRules {
+    // cards turned from the stock at a time
+    pub draw_count: usize,
+    pub pass_limit: PassLimit,
+    // turning the waste back over costs points in standard scoring
+    pub redeal_penalty: bool,
+    // otherwise only Kings go on empty columns
+    pub any_card_on_empty_column: bool,
+    pub foundation_to_tableau: bool,
+    // otherwise each foundation only takes its own suit
+    pub any_suit_foundations: bool,
+    // otherwise only the whole face-up run of a column moves
+    pub partial_runs: bool,
+}
+
+impl Default for KlondikeRules {
+    fn default() -> KlondikeRules {
+        KlondikeRules {
+            draw_count: MIN_DRAW_COUNT,
+            pass_limit: PassLimit::Unlimited,
+            redeal_penalty: true,
+            any_card_on_empty_column: false,
+            foundation_to_tableau: true,
+            any_suit_foundations: false,
+            partial_runs: true,
+        }
+    }
+}
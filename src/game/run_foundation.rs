@@ -0,0 +1,43 @@
+use crate::game::{MoveError, Pile, PileKind};
+use crate::deck::{Card, Rank, RANK_COUNT};
+
+// takes a whole suit at once, from the King down to the Ace, as Spider clears it off the tableau
+#[derive(Clone)]
+pub struct RunFoundation {
+    stack: Vec<Card>,
+}
+
+impl RunFoundation {
+    pub fn new() -> RunFoundation {
+        RunFoundation {
+            stack: Vec::with_capacity(RANK_COUNT),
+        }
+    }
+}
+
+impl Pile for RunFoundation {
+    fn get_kind(&self) -> PileKind {
+        PileKind::Foundation
+    }
+
+    fn get_cards(&self) -> &Vec<Card> {
+        &self.stack
+    }
+
+    fn get_cards_mut(&mut self) -> &mut Vec<Card> {
+        &mut self.stack
+    }
+
+    fn clone_box(&self) -> Box<dyn Pile> {
+        Box::new(self.clone())
+    }
+
+    fn check_push(&self, card: &Card) -> Result<(), MoveError> {
+        match self.stack.last() {
+            Some(last) if last.get_suit() != card.get_suit() => Err(MoveError::WrongSuit { card: card.clone(), suit: last.get_suit() }),
+            Some(last) if last.get_numbered_rank() != card.get_numbered_rank() + 1 => Err(MoveError::WrongRank { card: card.clone(), onto: last.clone() }),
+            None if card.get_rank() != Rank::King => Err(MoveError::NotKing { card: card.clone() }),
+            _ => Ok(()),
+        }
+    }
+}
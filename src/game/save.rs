@@ -0,0 +1,334 @@
+use std::time::Duration;
+use crate::deck::{Card, Suit};
+use crate::game::{FreeCell, Game, Klondike, Spider, Yukon, Pyramid, TriPeaks, Golf, Accordion, Clock, Canfield, FortyThieves, BakersDozen, BeleagueredCastle, Fortress, EightOff, PileId, PileKind, Scoring, Variant};
+use crate::game::history::{History, MoveCounts};
+use crate::game::timer::Timer;
+
+// the first line of every save file, followed by the format version
+const SAVE_HEADER: &str = "solitaire-rs save";
+const SAVE_FORMAT_VERSION: u32 = 1;
+
+// face-down cards are written with this prefix
+const CLOSED_PREFIX: char = '~';
+
+fn card_code(card: &Card) -> String {
+    if card.is_open() {
+        card.get_code()
+    } else {
+        format!("{}{}", CLOSED_PREFIX, card.get_code())
+    }
+}
+
+fn parse_card(input: &str) -> Result<Card, String> {
+    let code = input.strip_prefix(CLOSED_PREFIX);
+    let mut card = Card::from_code(code.unwrap_or(input)).ok_or_else(|| format!("{} is not a valid card.", input))?;
+    if code.is_none() {
+        card.open();
+    }
+
+    Ok(card)
+}
+
+// games that have no options to save
+fn without_options(options: &[&str], variant: Box<dyn Variant>) -> Result<Box<dyn Variant>, String> {
+    match options.first() {
+        Some(line) => Err(format!("Unknown entry {} in save file.", line.split_whitespace().next().unwrap_or(line))),
+        None => Ok(variant),
+    }
+}
+
+// the game a save file was written for, with the options it kept
+fn load_variant(name: &str, options: &[&str]) -> Result<Box<dyn Variant>, String> {
+    match name {
+        "klondike" => Ok(Box::new(Klondike::from_options(options)?)),
+        "spider" => Ok(Box::new(Spider::from_options(options)?)),
+        "freecell" => without_options(options, Box::new(FreeCell::new())),
+        "yukon" => without_options(options, Box::new(Yukon::new(false))),
+        "russian" => without_options(options, Box::new(Yukon::new(true))),
+        "pyramid" => without_options(options, Box::new(Pyramid::new())),
+        "tripeaks" => without_options(options, Box::new(TriPeaks::new())),
+        "golf" => without_options(options, Box::new(Golf::new())),
+        "accordion" => without_options(options, Box::new(Accordion::new())),
+        "clock" => without_options(options, Box::new(Clock::new())),
+        "canfield" => without_options(options, Box::new(Canfield::new())),
+        "fortythieves" => without_options(options, Box::new(FortyThieves::new())),
+        "bakersdozen" => without_options(options, Box::new(BakersDozen::new())),
+        "beleagueredcastle" => without_options(options, Box::new(BeleagueredCastle::new())),
+        "fortress" => without_options(options, Box::new(Fortress::new())),
+        "eightoff" => without_options(options, Box::new(EightOff::new())),
+        _ => Err(format!("Unknown game {} in save file.", name)),
+    }
+}
+
+// the pile a line is about
+fn parse_pile_id<'a>(kind: PileKind, tokens: &mut impl Iterator<Item = &'a str>) -> Result<PileId, String> {
+    if kind.is_single() {
+        return Ok(PileId::new(kind, 1));
+    }
+
+    tokens.next()
+        .and_then(|token| token.parse().ok())
+        .map(|number| PileId::new(kind, number))
+        .ok_or_else(|| format!("The {} number is not valid.", kind.get_name()))
+}
+
+// keeps track of which cards were already read, so no card is lost or duplicated
+struct CardCheck {
+    // how many of each card are still to come
+    left: Vec<usize>,
+}
+
+impl CardCheck {
+    fn new(cards: &[Card]) -> CardCheck {
+        let mut left = vec![0; 52];
+        for card in cards {
+            left[CardCheck::index(card)] += 1;
+        }
+
+        CardCheck {
+            left,
+        }
+    }
+
+    fn index(card: &Card) -> usize {
+        let suit = match card.get_suit() {
+            Suit::Clubs => 0,
+            Suit::Diamonds => 1,
+            Suit::Hearts => 2,
+            Suit::Spades => 3,
+        };
+        suit * 13 + card.get_numbered_rank() - 1
+    }
+
+    fn check(&mut self, card: &Card) -> Result<(), String> {
+        let i = CardCheck::index(card);
+        if self.left[i] == 0 {
+            return Err(format!("{} appears more than once.", card_code(card)));
+        }
+        self.left[i] -= 1;
+
+        Ok(())
+    }
+
+    fn is_complete(&self) -> bool {
+        self.left.iter().all(|left| *left == 0)
+    }
+}
+
+fn parse_cards<'a>(tokens: impl Iterator<Item = &'a str>, check: &mut CardCheck) -> Result<Vec<Card>, String> {
+    let mut cards = Vec::new();
+    for token in tokens {
+        let card = parse_card(token)?;
+        check.check(&card)?;
+        cards.push(card);
+    }
+
+    Ok(cards)
+}
+
+impl Game {
+    pub fn to_save(&self) -> String {
+        let mut out = format!("{} {}\n", SAVE_HEADER, SAVE_FORMAT_VERSION);
+
+        out.push_str(&format!("variant {}\n", self.variant.get_name()));
+        out.push_str(&self.variant.write_options());
+        out.push_str(&format!("deal-number {}\n", self.deal_number));
+        out.push_str(&format!("recycles {}\n", self.board.get_recycles()));
+        out.push_str(&format!("scoring {}\n", self.scoring));
+        out.push_str(&format!("score {}\n", self.score));
+        out.push_str(&format!("bankroll {}\n", self.bankroll));
+        out.push_str(&format!("elapsed-ms {}\n", self.get_elapsed().as_millis()));
+
+        let counts = &self.move_counts;
+        out.push_str(&format!(
+            "moves {} {} {} {} {} {} {} {}\n",
+            counts.deals,
+            counts.recycles,
+            counts.waste_to_foundation,
+            counts.waste_to_tableau,
+            counts.tableau_to_foundation,
+            counts.tableau_to_tableau,
+            counts.foundation_to_tableau,
+            counts.undos,
+        ));
+
+        for id in self.board.get_ids() {
+            out.push_str(&id.to_string());
+            for card in self.board.get_cards(*id) {
+                out.push(' ');
+                out.push_str(&card_code(card));
+            }
+            out.push('\n');
+        }
+
+        out
+    }
+
+    pub fn from_save(input: &str) -> Result<Game, String> {
+        let mut lines = input.lines();
+
+        let header = lines.next().unwrap_or("");
+        let version: u32 = match header.strip_prefix(SAVE_HEADER) {
+            Some(version) => version.trim().parse().map_err(|_| String::from("The save file has no version."))?,
+            None => return Err(String::from("This is not a solitaire save file.")),
+        };
+        if version > SAVE_FORMAT_VERSION {
+            return Err(format!("The save file version {} is newer than this game supports.", version));
+        }
+
+        let mut variant_name: Option<&str> = None;
+        // lines the game itself reads back
+        let mut options: Vec<&str> = Vec::new();
+        let mut deal_number: Option<u64> = None;
+        let mut recycles = 0;
+        let mut scoring: Option<Scoring> = None;
+        let mut score: Option<i32> = None;
+        let mut bankroll = 0;
+        let mut elapsed: Option<Duration> = None;
+        let mut move_counts: Option<MoveCounts> = None;
+        // the piles are filled once the game is known
+        let mut piles: Vec<(PileId, Vec<&str>)> = Vec::new();
+
+        for line in lines {
+            let mut tokens = line.split_whitespace();
+
+            match tokens.next() {
+                Some("variant") => {
+                    variant_name = Some(tokens.next().ok_or_else(|| String::from("The game is not valid."))?);
+                },
+                Some("deal-number") => {
+                    deal_number = tokens.next().and_then(|number| number.parse().ok());
+                },
+                Some("recycles") => {
+                    recycles = tokens.next().and_then(|number| number.parse().ok()).ok_or_else(|| String::from("The number of recycles is not valid."))?;
+                },
+                Some("scoring") => {
+                    scoring = match tokens.next() {
+                        Some("standard") => Some(Scoring::Standard),
+                        Some("vegas") => Some(Scoring::Vegas),
+                        _ => return Err(String::from("The scoring is not valid.")),
+                    };
+                },
+                Some("score") => {
+                    score = Some(tokens.next().and_then(|number| number.parse().ok()).ok_or_else(|| String::from("The score is not valid."))?);
+                },
+                Some("bankroll") => {
+                    bankroll = tokens.next().and_then(|number| number.parse().ok()).ok_or_else(|| String::from("The bankroll is not valid."))?;
+                },
+                Some("elapsed-ms") => {
+                    let ms = tokens.next().and_then(|number| number.parse().ok()).ok_or_else(|| String::from("The elapsed time is not valid."))?;
+                    elapsed = Some(Duration::from_millis(ms));
+                },
+                Some("moves") => {
+                    let counts: Vec<usize> = tokens.filter_map(|number| number.parse().ok()).collect();
+                    if counts.len() != 8 {
+                        return Err(String::from("The move counts are not valid."));
+                    }
+                    move_counts = Some(MoveCounts {
+                        deals: counts[0],
+                        recycles: counts[1],
+                        waste_to_foundation: counts[2],
+                        waste_to_tableau: counts[3],
+                        tableau_to_foundation: counts[4],
+                        tableau_to_tableau: counts[5],
+                        foundation_to_tableau: counts[6],
+                        undos: counts[7],
+                    });
+                },
+                Some(key) => match PileKind::from_name(key) {
+                    Some(kind) => {
+                        let id = parse_pile_id(kind, &mut tokens)?;
+                        piles.push((id, tokens.collect()));
+                    },
+                    None => options.push(line),
+                },
+                None => continue,
+            }
+        }
+
+        let variant = load_variant(variant_name.ok_or_else(|| String::from("The save file does not say which game it is."))?, &options)?;
+        let deal_number = deal_number.ok_or_else(|| String::from("The save file has no deal number."))?;
+        let scoring = scoring.ok_or_else(|| String::from("The save file has no scoring."))?;
+        let score = score.ok_or_else(|| String::from("The save file has no score."))?;
+        let elapsed = elapsed.ok_or_else(|| String::from("The save file has no elapsed time."))?;
+        let move_counts = move_counts.ok_or_else(|| String::from("The save file has no move counts."))?;
+
+        // the deal tells which piles there are and which cards belong on them
+        let mut board = variant.deal(deal_number);
+        let mut check = CardCheck::new(&board.clear());
+        for (id, tokens) in piles {
+            let cards = parse_cards(tokens.into_iter(), &mut check)?;
+            let pile = board.get_pile_mut(id).ok_or_else(|| format!("There is no {} in this game.", id))?;
+            for card in cards {
+                // the foundations have to be built in order, the rest was dealt and played into shape
+                if id.kind == PileKind::Foundation {
+                    if pile.push(card).is_some() {
+                        return Err(format!("The {} pile is not valid.", id));
+                    }
+                } else {
+                    pile.place(card);
+                }
+            }
+        }
+
+        if !check.is_complete() {
+            return Err(String::from("The save file is missing cards."));
+        }
+        board.set_recycles(recycles);
+
+        Ok(Game {
+            variant,
+            board,
+            deal_number,
+
+            scoring,
+            score,
+            bankroll,
+
+            history: History::new(),
+            move_counts,
+            timer: Timer::new(elapsed),
+
+            hint: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::KlondikeRules;
+
+    #[test]
+    fn a_saved_game_loads_back_the_same() {
+        let mut game = Game::new(Box::new(Klondike::new(KlondikeRules::default())), 9, Scoring::Vegas);
+        for i in 0..30 {
+            let moves = game.legal_moves();
+            if moves.is_empty() {
+                break;
+            }
+            assert!(game.play(moves[i % moves.len()]).is_ok());
+        }
+
+        let text = game.to_save();
+        let loaded = Game::from_save(&text).unwrap();
+
+        assert_eq!(loaded.to_save(), text);
+        assert_eq!(loaded.position_key(), game.position_key());
+    }
+
+    #[test]
+    fn a_save_missing_a_line_or_a_card_is_rejected() {
+        let text = Game::new(Box::new(Klondike::new(KlondikeRules::default())), 9, Scoring::Standard).to_save();
+
+        let without_score: String = text.lines().filter(|line| !line.starts_with("score ")).map(|line| format!("{}\n", line)).collect();
+        assert!(Game::from_save(&without_score).is_err());
+
+        let newer = text.replacen(&format!("{} {}", SAVE_HEADER, SAVE_FORMAT_VERSION), &format!("{} {}", SAVE_HEADER, SAVE_FORMAT_VERSION + 1), 1);
+        assert!(Game::from_save(&newer).is_err());
+
+        // the last line ends with a card
+        let without_card = &text[..text.rfind(' ').unwrap()];
+        assert!(Game::from_save(without_card).is_err());
+    }
+}
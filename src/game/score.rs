@@ -0,0 +1,80 @@
+use std::fmt;
+use crate::game::history::Record;
+use crate::game::PileKind;
+
+const VEGAS_BUY_IN: i32 = 52;
+const VEGAS_FOUNDATION_POINTS: i32 = 5;
+
+const STANDARD_FOUNDATION_POINTS: i32 = 10;
+const STANDARD_WASTE_TO_TABLEAU_POINTS: i32 = 5;
+const STANDARD_FLIP_POINTS: i32 = 5;
+const STANDARD_FOUNDATION_TO_TABLEAU_POINTS: i32 = -15;
+const STANDARD_RECYCLE_POINTS: i32 = -100;
+const STANDARD_DRAW_THREE_RECYCLE_POINTS: i32 = -20;
+
+// games won faster than this get no time bonus
+const TIME_BONUS_MIN_SECONDS: u64 = 30;
+const TIME_BONUS_POINTS: u64 = 700_000;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Scoring {
+    Standard,
+    Vegas,
+}
+
+impl Scoring {
+    pub fn initial_score(&self) -> i32 {
+        match self {
+            Scoring::Standard => 0,
+            Scoring::Vegas => -VEGAS_BUY_IN,
+        }
+    }
+
+    // points earned by a move, given the score before it
+    pub fn points(&self, record: &Record, draws_one: bool, redeal_penalty: bool, score: i32) -> i32 {
+        match self {
+            Scoring::Standard => {
+                let mut points = match record.mv.get_kinds() {
+                    Some((PileKind::Foundation, _)) => STANDARD_FOUNDATION_TO_TABLEAU_POINTS,
+                    Some((_, PileKind::Foundation)) => STANDARD_FOUNDATION_POINTS,
+                    Some((PileKind::Waste, PileKind::Tableau)) => STANDARD_WASTE_TO_TABLEAU_POINTS,
+                    None if record.recycled && redeal_penalty && draws_one => STANDARD_RECYCLE_POINTS,
+                    None if record.recycled && redeal_penalty => STANDARD_DRAW_THREE_RECYCLE_POINTS,
+                    _ => 0,
+                };
+                if record.flipped {
+                    points += STANDARD_FLIP_POINTS;
+                }
+                points += STANDARD_FOUNDATION_POINTS * record.collected as i32;
+
+                // the standard score never goes below zero
+                points.max(-score)
+            },
+            Scoring::Vegas => {
+                let points = match record.mv.get_kinds() {
+                    Some((PileKind::Foundation, _)) => -VEGAS_FOUNDATION_POINTS,
+                    Some((_, PileKind::Foundation)) => VEGAS_FOUNDATION_POINTS,
+                    _ => 0,
+                };
+                points + VEGAS_FOUNDATION_POINTS * record.collected as i32
+            },
+        }
+    }
+
+    // extra points for winning quickly
+    pub fn time_bonus(&self, seconds: u64) -> i32 {
+        match self {
+            Scoring::Standard if seconds >= TIME_BONUS_MIN_SECONDS => (TIME_BONUS_POINTS / seconds) as i32,
+            _ => 0,
+        }
+    }
+}
+
+impl fmt::Display for Scoring {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Scoring::Standard => write!(f, "standard"),
+            Scoring::Vegas => write!(f, "vegas"),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::game::{MoveError, Pile, PileKind};
+use crate::deck::Card;
+
+// the place of one card in an overlapping layout, which only ever loses its card
+#[derive(Clone)]
+pub struct Slot {
+    stack: Vec<Card>,
+}
+
+impl Slot {
+    pub fn new(card: Card) -> Slot {
+        Slot {
+            stack: vec![card],
+        }
+    }
+}
+
+impl Pile for Slot {
+    fn get_kind(&self) -> PileKind {
+        PileKind::Slot
+    }
+
+    fn get_cards(&self) -> &Vec<Card> {
+        &self.stack
+    }
+
+    fn get_cards_mut(&mut self) -> &mut Vec<Card> {
+        &mut self.stack
+    }
+
+    fn clone_box(&self) -> Box<dyn Pile> {
+        Box::new(self.clone())
+    }
+
+    fn check_push(&self, _card: &Card) -> Result<(), MoveError> {
+        if self.stack.is_empty() {
+            Ok(())
+        } else {
+            Err(MoveError::SlotTaken)
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use std::fmt;
+use crate::deck::{Card, Deck, Suit, RANK_COUNT};
+use crate::game::board::{Board, Op};
+use crate::game::render;
+use crate::game::run_foundation::RunFoundation;
+use crate::game::spider_tableau::{self, SpiderTableau};
+use crate::game::stock::Stock;
+use crate::game::variant::{self, Variant};
+use crate::game::{MoveError, PileId, PileKind, Spot, SUITS};
+
+const TABLEAU_COUNT: usize = 10;
+// the first columns get one more card than the rest
+const LONG_COLUMNS: usize = 4;
+const LONG_COLUMN_SIZE: usize = 6;
+const FOUNDATION_COUNT: usize = 8;
+// the two decks hold eight suits' worth of cards, however many suits there are
+const SUIT_SETS: usize = 8;
+
+const STOCK: PileId = PileId { kind: PileKind::Stock, number: 1 };
+
+// the suits played with, fewer suits make for an easier game
+fn suits_for(suit_count: usize) -> &'static [Suit] {
+    match suit_count {
+        1 => &SUITS[3..],
+        2 => &SUITS[2..],
+        _ => &SUITS,
+    }
+}
+
+// two decks built down regardless of suit, but only a run of one suit from King to Ace leaves the table
+#[derive(Clone)]
+pub struct Spider {
+    suit_count: usize,
+}
+
+impl Spider {
+    pub const SUIT_COUNTS: [usize; 3] = [1, 2, 4];
+
+    pub fn new(suit_count: usize) -> Spider {
+        Spider {
+            suit_count,
+        }
+    }
+
+    pub fn from_options(lines: &[&str]) -> Result<Spider, String> {
+        let mut suit_count = None;
+
+        for line in lines {
+            let mut tokens = line.split_whitespace();
+
+            match tokens.next() {
+                Some("suits") => {
+                    suit_count = match tokens.next().and_then(|number| number.parse().ok()) {
+                        Some(count) if Spider::SUIT_COUNTS.contains(&count) => Some(count),
+                        _ => return Err(String::from("The number of suits is not valid.")),
+                    };
+                },
+                Some(key) => return Err(format!("Unknown entry {} in save file.", key)),
+                None => continue,
+            }
+        }
+
+        suit_count.map(Spider::new).ok_or_else(|| String::from("The save file has no number of suits."))
+    }
+
+    // the columns ending in a whole suit from King to Ace, face-up
+    fn complete_runs(&self, board: &Board) -> Vec<PileId> {
+        board.ids_of(PileKind::Tableau)
+            .into_iter()
+            .filter(|id| {
+                let cards = board.get_cards(*id);
+                cards.len() >= RANK_COUNT && {
+                    let run = &cards[cards.len() - RANK_COUNT..];
+                    run[0].get_numbered_rank() == RANK_COUNT
+                        && run.iter().all(|card| card.is_open())
+                        && run.windows(2).all(|pair| spider_tableau::can_follow(&pair[1], &pair[0]))
+                }
+            })
+            .collect()
+    }
+}
+
+impl Variant for Spider {
+    fn get_name(&self) -> &'static str {
+        "spider"
+    }
+
+    fn clone_box(&self) -> Box<dyn Variant> {
+        Box::new(self.clone())
+    }
+
+    fn get_title(&self) -> String {
+        match self.suit_count {
+            1 => String::from("Spider 1 suit"),
+            count => format!("Spider {} suits", count),
+        }
+    }
+
+    fn deal(&self, deal_number: u64) -> Board {
+        let suits = suits_for(self.suit_count);
+        let mut deck = Deck::from_seed_with_suits(deal_number, suits, SUIT_SETS / suits.len());
+
+        let columns: Vec<Vec<Card>> = (0..TABLEAU_COUNT)
+            .map(|i| {
+                let size = if i < LONG_COLUMNS { LONG_COLUMN_SIZE } else { LONG_COLUMN_SIZE - 1 };
+                (1..=size).filter_map(|j| deck.deal(j == size)).collect()
+            })
+            .collect();
+
+        let mut board = Board::new();
+        board.add(Box::new(Stock::new(deck.cards)));
+        for _ in 0..FOUNDATION_COUNT {
+            board.add(Box::new(RunFoundation::new()));
+        }
+        for column in columns {
+            board.add(Box::new(SpiderTableau::new(column)));
+        }
+
+        board
+    }
+
+    fn render(&self, board: &Board, hinted: &dyn Fn(Spot) -> bool, f: &mut fmt::Formatter) -> fmt::Result {
+        for id in board.ids_of(PileKind::Foundation) {
+            render::write_top(f, board, id, '\u{00B7}', hinted)?;
+        }
+
+        // every deal puts a card on each column
+        write!(f, "  ")?;
+        render::write_top(f, board, STOCK, '\u{1F0EA}', hinted)?;
+        writeln!(f, " {} deal(s) left\n", board.get_len(STOCK) / TABLEAU_COUNT)?;
+
+        render::write_columns(f, board, &board.ids_of(PileKind::Tableau), hinted)
+    }
+
+    fn write_options(&self) -> String {
+        format!("suits {}\n", self.suit_count)
+    }
+
+    fn deal_ops(&self, board: &Board) -> Result<Vec<Op>, MoveError> {
+        if board.get_len(STOCK) == 0 {
+            return Err(MoveError::NothingToDeal);
+        }
+
+        let columns = board.ids_of(PileKind::Tableau);
+        if columns.iter().any(|id| board.get_len(*id) == 0) {
+            return Err(MoveError::EmptyColumn);
+        }
+
+        Ok(columns.into_iter().map(|to| Op::Turn { from: STOCK, to, count: 1 }).collect())
+    }
+
+    fn check_put(&self, board: &Board, to: PileId, card: &Card, size: usize) -> Result<(), MoveError> {
+        if to.kind == PileKind::Foundation {
+            return Err(MoveError::CompleteRunsOnly);
+        }
+
+        variant::check_pile_put(board, to, card, size)
+    }
+
+    fn check_take(&self, board: &Board, from: PileId, size: usize) -> Result<(), MoveError> {
+        if from.kind == PileKind::Foundation {
+            return Err(MoveError::CannotMoveFrom(from));
+        }
+
+        variant::check_pile_take(board, from, size)
+    }
+
+    // complete runs go to the foundations first, then the cards they uncover are turned up
+    fn after_move(&self, board: &Board) -> Vec<Op> {
+        let runs = self.complete_runs(board);
+        let foundations = board.ids_of(PileKind::Foundation)
+            .into_iter()
+            .filter(|id| board.get_len(*id) == 0);
+
+        let ops: Vec<Op> = runs.into_iter()
+            .zip(foundations)
+            .map(|(from, to)| Op::Move { from, to, count: RANK_COUNT })
+            .collect();
+        if !ops.is_empty() {
+            return ops;
+        }
+
+        variant::flip_tops(board)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::tests::{position, rest_of_deck};
+    use crate::game::Move;
+
+    fn column(number: usize) -> PileId {
+        PileId::new(PileKind::Tableau, number)
+    }
+
+    fn foundation(number: usize) -> PileId {
+        PileId::new(PileKind::Foundation, number)
+    }
+
+    #[test]
+    fn a_finished_run_leaves_the_table_and_comes_back_on_undo() {
+        // the King down to the 2 of spades over a hidden Ace, waiting on the last Ace
+        let mut piles = vec![
+            "tableau 1 ~AS KS QS JS 10S 9S 8S 7S 6S 5S 4S 3S 2S".to_string(),
+            "tableau 2 AS".to_string(),
+        ];
+        piles.push(format!("stock {}", rest_of_deck(Box::new(Spider::new(1)), &piles)));
+        let mut game = position(Box::new(Spider::new(1)), &piles);
+
+        assert!(game.play(Move::Transfer(column(2), column(1), 1)).is_ok());
+        assert_eq!(game.get_pile_len(foundation(1)), RANK_COUNT);
+        assert_eq!(game.get_pile_len(column(1)), 1);
+        assert_eq!(game.get_pile_len(column(2)), 0);
+        assert!(game.board.get_cards(column(1))[0].is_open());
+
+        assert!(game.undo().is_some());
+        assert_eq!(game.get_pile_len(foundation(1)), 0);
+        assert_eq!(game.get_pile_len(column(1)), 13);
+        assert_eq!(game.get_pile_len(column(2)), 1);
+        assert!(!game.board.get_cards(column(1))[0].is_open());
+    }
+}
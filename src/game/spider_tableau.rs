@@ -0,0 +1,60 @@
+use crate::game::{MoveError, Pile, PileKind};
+use crate::deck::Card;
+
+// a card goes on any card one rank higher, but only runs of one suit move together
+pub fn can_follow(card: &Card, onto: &Card) -> bool {
+    onto.get_numbered_rank() == card.get_numbered_rank() + 1 && onto.get_suit() == card.get_suit()
+}
+
+#[derive(Clone)]
+pub struct SpiderTableau {
+    stack: Vec<Card>,
+}
+
+impl SpiderTableau {
+    pub fn new(stack: Vec<Card>) -> SpiderTableau {
+        SpiderTableau {
+            stack,
+        }
+    }
+}
+
+impl Pile for SpiderTableau {
+    fn get_kind(&self) -> PileKind {
+        PileKind::Tableau
+    }
+
+    fn get_cards(&self) -> &Vec<Card> {
+        &self.stack
+    }
+
+    fn get_cards_mut(&mut self) -> &mut Vec<Card> {
+        &mut self.stack
+    }
+
+    fn clone_box(&self) -> Box<dyn Pile> {
+        Box::new(self.clone())
+    }
+
+    // any card goes on an empty column
+    fn check_push(&self, card: &Card) -> Result<(), MoveError> {
+        match self.stack.last() {
+            Some(last) if last.get_numbered_rank() != card.get_numbered_rank() + 1 => Err(MoveError::WrongRank { card: card.clone(), onto: last.clone() }),
+            _ => Ok(()),
+        }
+    }
+
+    fn moves_runs(&self) -> bool {
+        true
+    }
+
+    fn check_run(&self, run: &[Card]) -> Result<(), MoveError> {
+        for pair in run.windows(2) {
+            if !can_follow(&pair[1], &pair[0]) {
+                return Err(MoveError::BrokenRun { card: pair[1].clone(), onto: pair[0].clone() });
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
-use crate::game::Pile;
+use crate::game::{MoveError, Pile, PileId, PileKind};
 use crate::deck::Card;
 
+#[derive(Clone)]
 pub struct Stock {
     stack: Vec<Card>
 }
@@ -14,32 +15,24 @@ impl Stock {
 }
 
 impl Pile for Stock {
-    fn can_push(&self, card: &Card) -> bool {
-        !card.is_open()
+    fn get_kind(&self) -> PileKind {
+        PileKind::Stock
     }
 
-    fn push(&mut self, card: Card) -> Option<Card> {
-        if self.can_push(&card) {
-            self.stack.push(card);
-            None
-        } else {
-            Some(card)
-        }
-    }
-
-    fn pop(&mut self) -> Option<Card> {
-        self.stack.pop()
+    fn get_cards(&self) -> &Vec<Card> {
+        &self.stack
     }
 
-    fn len(&self) -> usize {
-        self.stack.len()
+    fn get_cards_mut(&mut self) -> &mut Vec<Card> {
+        &mut self.stack
     }
 
-    fn last(&self) -> Option<&Card> {
-        self.stack.last()
+    fn clone_box(&self) -> Box<dyn Pile> {
+        Box::new(self.clone())
     }
 
-    fn get(&self, i: usize) -> Option<&Card> {
-        self.stack.get(i)
+    // cards only come back to the stock when the waste is turned over
+    fn check_push(&self, _card: &Card) -> Result<(), MoveError> {
+        Err(MoveError::CannotMoveTo(PileId::new(PileKind::Stock, 1)))
     }
-}
\ No newline at end of file
+}
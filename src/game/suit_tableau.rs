@@ -0,0 +1,67 @@
+use crate::game::{MoveError, Pile, PileKind};
+use crate::deck::{Card, Rank};
+
+// builds down in suit, one card at a time
+#[derive(Clone)]
+pub struct SuitTableau {
+    stack: Vec<Card>,
+    // otherwise only a King goes on the empty column
+    any_card_when_empty: bool,
+    // builds up in suit as well
+    both_ways: bool,
+}
+
+impl SuitTableau {
+    pub fn new(stack: Vec<Card>, any_card_when_empty: bool) -> SuitTableau {
+        SuitTableau {
+            stack,
+            any_card_when_empty,
+            both_ways: false,
+        }
+    }
+
+    // a column building up or down in suit, any card going on it once it is empty
+    pub fn both_ways(stack: Vec<Card>) -> SuitTableau {
+        SuitTableau {
+            both_ways: true,
+            ..SuitTableau::new(stack, true)
+        }
+    }
+}
+
+impl Pile for SuitTableau {
+    fn get_kind(&self) -> PileKind {
+        PileKind::Tableau
+    }
+
+    fn get_cards(&self) -> &Vec<Card> {
+        &self.stack
+    }
+
+    fn get_cards_mut(&mut self) -> &mut Vec<Card> {
+        &mut self.stack
+    }
+
+    fn clone_box(&self) -> Box<dyn Pile> {
+        Box::new(self.clone())
+    }
+
+    fn check_push(&self, card: &Card) -> Result<(), MoveError> {
+        let last = match self.stack.last() {
+            Some(last) => last,
+            None if !self.any_card_when_empty && card.get_rank() != Rank::King => return Err(MoveError::NotKing { card: card.clone() }),
+            None => return Ok(()),
+        };
+
+        let down = last.get_numbered_rank() == card.get_numbered_rank() + 1;
+        let up = self.both_ways && last.get_numbered_rank() + 1 == card.get_numbered_rank();
+        if !down && !up {
+            return Err(MoveError::WrongRank { card: card.clone(), onto: last.clone() });
+        }
+        if last.get_suit() != card.get_suit() {
+            return Err(MoveError::DifferentSuit { card: card.clone(), onto: last.clone() });
+        }
+
+        Ok(())
+    }
+}
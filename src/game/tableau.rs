@@ -1,74 +1,75 @@
-use crate::game::Pile;
-use crate::deck::{Card, Suit, Rank};
+use crate::game::{MoveError, Pile, PileKind};
+use crate::deck::{Card, Rank};
 
+// a card goes on one of the other color and one rank higher
+pub fn can_stack(card: &Card, onto: &Card) -> bool {
+    onto.get_numbered_rank() == card.get_numbered_rank() + 1 && onto.get_suit().is_red() != card.get_suit().is_red()
+}
+
+// why a card cannot go on another, building down in alternate colors
+pub fn check_stack(card: &Card, onto: &Card) -> Result<(), MoveError> {
+    if onto.get_numbered_rank() != card.get_numbered_rank() + 1 {
+        return Err(MoveError::WrongRank { card: card.clone(), onto: onto.clone() });
+    }
+    if onto.get_suit().is_red() == card.get_suit().is_red() {
+        return Err(MoveError::SameColor { card: card.clone(), onto: onto.clone() });
+    }
+
+    Ok(())
+}
+
+#[derive(Clone)]
 pub struct Tableau {
-    stack: Vec<Card>
+    stack: Vec<Card>,
+    // otherwise only a King goes on the empty column
+    any_card_when_empty: bool,
 }
 
 impl Tableau {
-    pub fn new(stack: Vec<Card>) -> Tableau {
+    pub fn new(stack: Vec<Card>, any_card_when_empty: bool) -> Tableau {
         Tableau {
-            stack
+            stack,
+            any_card_when_empty,
         }
     }
 }
 
 impl Pile for Tableau {
-    fn can_push(&self, card: &Card) -> bool {
-        if !card.is_open() {
-            return false;
-        }
-
-        if let Some(last) = self.stack.last() {
-            if last.get_numbered_rank() - 1 != card.get_numbered_rank() {
-                return false;
-            }
-
-            if (last.get_suit() == Suit::Diamonds || last.get_suit() == Suit::Hearts) && (card.get_suit() != Suit::Clubs && card.get_suit() != Suit::Spades) {
-                return false;
-            }
-
-            if (last.get_suit() == Suit::Clubs || last.get_suit() == Suit::Spades) && (card.get_suit() != Suit::Diamonds && card.get_suit() != Suit::Hearts) {
-                return false;
-            }
+    fn get_kind(&self) -> PileKind {
+        PileKind::Tableau
+    }
 
-        } else if card.get_rank() != Rank::King {
-            return false;
-        };
+    fn get_cards(&self) -> &Vec<Card> {
+        &self.stack
+    }
 
-        true
+    fn get_cards_mut(&mut self) -> &mut Vec<Card> {
+        &mut self.stack
     }
 
-    fn push(&mut self, card: Card) -> Option<Card> {
-        if self.can_push(&card) {
-            self.stack.push(card);
-            None
-        } else {
-            Some(card)
-        }
+    fn clone_box(&self) -> Box<dyn Pile> {
+        Box::new(self.clone())
     }
 
-    fn pop(&mut self) -> Option<Card> {
-        if let Some(card) = self.stack.pop() {
-            if let Some(mut card_2) = self.stack.pop() {
-                card_2.open();
-                self.stack.push(card_2);
-            }
-            return Some(card);
+    fn check_push(&self, card: &Card) -> Result<(), MoveError> {
+        match self.stack.last() {
+            Some(last) => check_stack(card, last),
+            None if !self.any_card_when_empty && card.get_rank() != Rank::King => Err(MoveError::NotKing { card: card.clone() }),
+            None => Ok(()),
         }
-
-        None
     }
 
-    fn len(&self) -> usize {
-        self.stack.len()
+    fn moves_runs(&self) -> bool {
+        true
     }
 
-    fn last(&self) -> Option<&Card> {
-        self.stack.last()
-    }
+    fn check_run(&self, run: &[Card]) -> Result<(), MoveError> {
+        for pair in run.windows(2) {
+            if !can_stack(&pair[1], &pair[0]) {
+                return Err(MoveError::BrokenRun { card: pair[1].clone(), onto: pair[0].clone() });
+            }
+        }
 
-    fn get(&self, i: usize) -> Option<&Card> {
-        self.stack.get(i)
+        Ok(())
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,36 @@
+use std::time::{Duration, Instant};
+
+// play time that only runs while the game is on screen
+#[derive(Clone)]
+pub struct Timer {
+    elapsed: Duration,
+    running_since: Option<Instant>,
+}
+
+impl Timer {
+    pub fn new(elapsed: Duration) -> Timer {
+        Timer {
+            elapsed,
+            running_since: None,
+        }
+    }
+
+    pub fn resume(&mut self) {
+        if self.running_since.is_none() {
+            self.running_since = Some(Instant::now());
+        }
+    }
+
+    pub fn pause(&mut self) {
+        if let Some(since) = self.running_since.take() {
+            self.elapsed += since.elapsed();
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        match self.running_since {
+            Some(since) => self.elapsed + since.elapsed(),
+            None => self.elapsed,
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use std::fmt;
+use crate::deck::{Card, Deck};
+use crate::game::board::{Board, Op};
+use crate::game::discard::{self, Discard};
+use crate::game::layout::Layout;
+use crate::game::render;
+use crate::game::slot::Slot;
+use crate::game::stock::Stock;
+use crate::game::variant::{self, Variant};
+use crate::game::{MoveError, PileId, PileKind, Spot};
+
+// only the bottom row of the peaks is dealt face-up
+const OPEN_ROW: usize = 3;
+
+const STOCK: PileId = PileId { kind: PileKind::Stock, number: 1 };
+const FOUNDATION: PileId = PileId { kind: PileKind::Foundation, number: 1 };
+
+// three peaks of 28 cards cleared onto a single foundation, one rank up or down from its top card
+#[derive(Clone)]
+pub struct TriPeaks {
+    layout: Layout,
+}
+
+impl TriPeaks {
+    pub fn new() -> TriPeaks {
+        TriPeaks {
+            layout: Layout::tri_peaks(),
+        }
+    }
+}
+
+impl Variant for TriPeaks {
+    fn get_name(&self) -> &'static str {
+        "tripeaks"
+    }
+
+    fn clone_box(&self) -> Box<dyn Variant> {
+        Box::new(self.clone())
+    }
+
+    fn get_title(&self) -> String {
+        String::from("TriPeaks")
+    }
+
+    fn deal(&self, deal_number: u64) -> Board {
+        let mut deck = Deck::from_seed(deal_number);
+        let slots: Vec<Slot> = (0..self.layout.len())
+            .map(|i| Slot::new(deck.deal(self.layout.get_row(i) == OPEN_ROW).unwrap()))
+            .collect();
+
+        let foundation = Discard::from_stock(&mut deck);
+
+        let mut board = Board::new();
+        board.add(Box::new(Stock::new(deck.cards)));
+        board.add(Box::new(foundation));
+        for slot in slots {
+            board.add(Box::new(slot));
+        }
+
+        board
+    }
+
+    fn render(&self, board: &Board, hinted: &dyn Fn(Spot) -> bool, f: &mut fmt::Formatter) -> fmt::Result {
+        self.layout.write(f, board, hinted)?;
+
+        render::write_top(f, board, FOUNDATION, '\u{00B7}', hinted)?;
+        write!(f, "  ")?;
+        render::write_top(f, board, STOCK, '\u{1F0EA}', hinted)?;
+        write!(f, " {} card(s) left\n\n", board.get_len(STOCK))
+    }
+
+    fn deal_ops(&self, board: &Board) -> Result<Vec<Op>, MoveError> {
+        if board.get_len(STOCK) == 0 {
+            return Err(MoveError::NothingToDeal);
+        }
+
+        Ok(vec![Op::Turn { from: STOCK, to: FOUNDATION, count: 1 }])
+    }
+
+    fn check_take(&self, board: &Board, from: PileId, size: usize) -> Result<(), MoveError> {
+        if from.kind != PileKind::Slot {
+            return Err(MoveError::CannotMoveFrom(from));
+        }
+
+        variant::check_pile_take(board, from, size)?;
+        self.layout.check_uncovered(board, from)
+    }
+
+    // a rank up or down, going round from King to Ace
+    fn check_put(&self, board: &Board, to: PileId, card: &Card, _size: usize) -> Result<(), MoveError> {
+        if to.kind != PileKind::Foundation {
+            return Err(MoveError::CannotMoveTo(to));
+        }
+
+        match board.last(to) {
+            Some(onto) => discard::check_next_rank(card, onto, true),
+            None => Ok(()),
+        }
+    }
+
+    fn after_move(&self, board: &Board) -> Vec<Op> {
+        self.layout.flip_uncovered(board)
+    }
+
+    // the peaks cleared, whatever is left in the stock
+    fn is_won(&self, board: &Board) -> bool {
+        board.count(PileKind::Slot) == 0
+    }
+
+    fn is_safe_on_foundation(&self, _board: &Board, _card: &Card) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::tests::{position, rest_of_deck};
+    use crate::game::Move;
+
+    fn slot(number: usize) -> PileId {
+        PileId::new(PileKind::Slot, number)
+    }
+
+    #[test]
+    fn a_card_a_rank_either_side_goes_up_going_round_from_king_to_ace() {
+        let mut piles = vec![
+            "foundation 1 KH".to_string(),
+            "slot 19 AS".to_string(),
+            "slot 20 3D".to_string(),
+            "slot 21 2C".to_string(),
+            "slot 22 QD".to_string(),
+        ];
+        piles.push(format!("stock {}", rest_of_deck(Box::new(TriPeaks::new()), &piles)));
+        let mut game = position(Box::new(TriPeaks::new()), &piles);
+
+        assert!(game.play(Move::Transfer(slot(20), FOUNDATION, 1)).is_err());
+        assert!(game.play(Move::Transfer(slot(19), FOUNDATION, 1)).is_ok());
+        assert!(game.play(Move::Transfer(slot(21), FOUNDATION, 1)).is_ok());
+        assert!(game.play(Move::Transfer(slot(20), FOUNDATION, 1)).is_ok());
+        assert!(game.play(Move::Transfer(slot(22), FOUNDATION, 1)).is_err());
+        assert_eq!(game.get_pile_len(FOUNDATION), 4);
+    }
+}
@@ -0,0 +1,266 @@
+use std::fmt;
+use crate::deck::{Card, Suit};
+use crate::game::board::{Board, Op};
+use crate::game::{Move, MoveError, PileId, PileKind, Spot, SUITS};
+
+// the order piles are tried in as the source of a move, and as its destination
+const SOURCE_KINDS: [PileKind; 6] = [PileKind::Waste, PileKind::Reserve, PileKind::Cell, PileKind::Slot, PileKind::Tableau, PileKind::Foundation];
+const TARGET_KINDS: [PileKind; 4] = [PileKind::Foundation, PileKind::Tableau, PileKind::Cell, PileKind::Slot];
+
+// why the top `size` cards of a pile cannot be picked up, by the rules every game shares
+pub fn check_pile_take(board: &Board, from: PileId, size: usize) -> Result<(), MoveError> {
+    let pile = board.get_pile(from).ok_or(MoveError::InvalidPile(from))?;
+    let len = pile.len();
+
+    if from.kind == PileKind::Stock {
+        return Err(MoveError::CannotMoveFrom(from));
+    }
+    if size == 0 {
+        return Err(MoveError::NoCards);
+    }
+    if len == 0 {
+        return Err(MoveError::EmptyPile(from));
+    }
+    if size > len {
+        return Err(MoveError::NotEnoughCards { pile: from, size, len });
+    }
+    if size > 1 && !pile.moves_runs() {
+        return Err(MoveError::SingleCardFrom(from));
+    }
+
+    let run = &pile.get_cards()[len - size..];
+    if run.iter().any(|card| !card.is_open()) {
+        return Err(MoveError::FaceDown { pile: from, size });
+    }
+
+    pile.check_run(run)
+}
+
+// why `size` cards starting with `card` cannot go on a pile
+pub fn check_pile_put(board: &Board, to: PileId, card: &Card, size: usize) -> Result<(), MoveError> {
+    let pile = board.get_pile(to).ok_or(MoveError::InvalidPile(to))?;
+
+    if size > 1 && !pile.moves_runs() {
+        return Err(MoveError::SingleCardTo(to));
+    }
+
+    pile.check_push(card)
+}
+
+// why a move cannot be played, taking and putting by the game's rules
+pub fn check_pile_move<V: Variant + ?Sized>(variant: &V, board: &Board, from: PileId, to: PileId, size: usize) -> Result<(), MoveError> {
+    if from == to {
+        return Err(MoveError::SamePile(from));
+    }
+    // the player never puts cards on these, whatever is being moved
+    if matches!(to.kind, PileKind::Stock | PileKind::Waste) {
+        return Err(MoveError::CannotMoveTo(to));
+    }
+    if from.kind == PileKind::Foundation && to.kind == PileKind::Foundation {
+        return Err(MoveError::CannotMoveTo(to));
+    }
+
+    variant.check_take(board, from, size)?;
+
+    let len = board.get_len(from);
+    let card = board.get_card(from, len - size).ok_or(MoveError::EmptyPile(from))?;
+    variant.check_put(board, to, card, size)
+}
+
+// turns cards of the stock onto the waste, or the waste back over once the stock runs out
+pub fn deal_to_waste<V: Variant + ?Sized>(variant: &V, board: &Board, stock: PileId, waste: PileId) -> Result<Vec<Op>, MoveError> {
+    let stock_len = board.get_len(stock);
+    if stock_len > 0 {
+        return Ok(vec![Op::Turn { from: stock, to: waste, count: stock_len.min(variant.get_draw_count()) }]);
+    }
+
+    let waste_len = board.get_len(waste);
+    if waste_len == 0 {
+        return Err(MoveError::NothingToDeal);
+    }
+    if variant.get_max_passes().is_some_and(|max_passes| board.get_recycles() + 1 >= max_passes) {
+        return Err(MoveError::NoPassesLeft);
+    }
+
+    Ok(vec![Op::Turn { from: waste, to: stock, count: waste_len }, Op::Recycle])
+}
+
+// turns up the face-down cards left on top of the tableaus and the reserve
+pub fn flip_tops(board: &Board) -> Vec<Op> {
+    board.get_ids()
+        .iter()
+        .filter(|id| matches!(id.kind, PileKind::Tableau | PileKind::Reserve))
+        .filter(|id| board.last(**id).is_some_and(|card| !card.is_open()))
+        .map(|id| Op::Flip(*id))
+        .collect()
+}
+
+// how many cards of a suit are on the foundations
+fn foundation_height(board: &Board, suit: Suit) -> usize {
+    board.foundation_of_suit(suit).map_or(0, |id| board.get_len(id))
+}
+
+// a card is safe on its foundation once no card that could be built on it
+// is still needed on a tableau built down in alternate colors
+pub fn is_safe_in_alternate_colors(board: &Board, card: &Card) -> bool {
+    let rank = card.get_numbered_rank();
+    if rank <= 2 {
+        return true;
+    }
+
+    let (same, opposite) = match card.get_suit() {
+        Suit::Clubs => ([Suit::Spades], [Suit::Diamonds, Suit::Hearts]),
+        Suit::Spades => ([Suit::Clubs], [Suit::Diamonds, Suit::Hearts]),
+        Suit::Diamonds => ([Suit::Hearts], [Suit::Clubs, Suit::Spades]),
+        Suit::Hearts => ([Suit::Diamonds], [Suit::Clubs, Suit::Spades]),
+    };
+
+    opposite.iter().all(|suit| foundation_height(board, *suit) + 1 >= rank)
+        && same.iter().all(|suit| foundation_height(board, *suit) + 2 >= rank)
+}
+
+// the same on a tableau built down regardless of suit, where any card one rank lower may need it
+pub fn is_safe_in_any_suit(board: &Board, card: &Card) -> bool {
+    let rank = card.get_numbered_rank();
+    SUITS.iter().filter(|suit| **suit != card.get_suit()).all(|suit| foundation_height(board, *suit) + 1 >= rank)
+}
+
+// every move out of one pile, the longest runs first, with a single foundation for each card
+// and a single empty pile of each kind, as the others would give the same position
+pub fn moves_from<V: Variant + ?Sized>(variant: &V, board: &Board, from: PileId) -> Vec<Move> {
+    let mut moves = Vec::new();
+    let len = board.get_len(from);
+    let targets: Vec<PileId> = TARGET_KINDS.iter()
+        .flat_map(|kind| board.ids_of(*kind))
+        .filter(|to| *to != from)
+        .collect();
+
+    for size in (1..=len).rev() {
+        if variant.check_take(board, from, size).is_err() {
+            continue;
+        }
+
+        let mut to_foundation = false;
+        let mut empty_kinds: Vec<PileKind> = Vec::new();
+        for to in targets.iter().copied() {
+            let empty = board.get_len(to) == 0;
+            if (to.kind == PileKind::Foundation && to_foundation) || (empty && empty_kinds.contains(&to.kind)) {
+                continue;
+            }
+            if variant.check_move(board, from, to, size).is_ok() {
+                to_foundation |= to.kind == PileKind::Foundation;
+                if empty {
+                    empty_kinds.push(to.kind);
+                }
+                moves.push(Move::Transfer(from, to, size));
+            }
+        }
+    }
+
+    moves
+}
+
+// the rules of one solitaire game: how the cards are laid out, dealt, moved and won
+pub trait Variant {
+    // the name saves and statistics know the game by
+    fn get_name(&self) -> &'static str;
+    fn clone_box(&self) -> Box<dyn Variant>;
+    // the name players see, with the options that make a difference to the statistics
+    fn get_title(&self) -> String;
+
+    // lays out the piles and deals the cards, the same deal number always deals the same game
+    fn deal(&self, deal_number: u64) -> Board;
+
+    // draws the board, marking the cards `hinted` picks out
+    fn render(&self, board: &Board, hinted: &dyn Fn(Spot) -> bool, f: &mut fmt::Formatter) -> fmt::Result;
+
+    // the lines a save file keeps the game's options in
+    fn write_options(&self) -> String {
+        String::new()
+    }
+
+    // what dealing from the stock does right now
+    fn deal_ops(&self, _board: &Board) -> Result<Vec<Op>, MoveError> {
+        Err(MoveError::NothingToDeal)
+    }
+
+    fn check_take(&self, board: &Board, from: PileId, size: usize) -> Result<(), MoveError> {
+        check_pile_take(board, from, size)
+    }
+
+    fn check_put(&self, board: &Board, to: PileId, card: &Card, size: usize) -> Result<(), MoveError> {
+        check_pile_put(board, to, card, size)
+    }
+
+    fn check_move(&self, board: &Board, from: PileId, to: PileId, size: usize) -> Result<(), MoveError> {
+        check_pile_move(self, board, from, to, size)
+    }
+
+    // the steps a move the rules allow makes on the board
+    fn move_ops(&self, _board: &Board, from: PileId, to: PileId, size: usize) -> Vec<Op> {
+        vec![Op::Move { from, to, count: size }]
+    }
+
+    // what happens by itself after every move, like turning up uncovered cards
+    fn after_move(&self, board: &Board) -> Vec<Op> {
+        flip_tops(board)
+    }
+
+    fn legal_moves(&self, board: &Board) -> Vec<Move> {
+        let mut moves = Vec::new();
+
+        if self.deal_ops(board).is_ok() {
+            moves.push(Move::Deal);
+        }
+
+        for kind in SOURCE_KINDS.iter() {
+            for from in board.ids_of(*kind) {
+                moves.extend(moves_from(self, board, from));
+            }
+        }
+
+        moves
+    }
+
+    // every card on the foundations
+    fn is_won(&self, board: &Board) -> bool {
+        board.count(PileKind::Foundation) == board.count_all()
+    }
+
+    // whether a card can go to its foundation with no chance of being needed on the tableau,
+    // for foundations built up in suit from the Ace and a tableau built down in alternate colors
+    fn is_safe_on_foundation(&self, board: &Board, card: &Card) -> bool {
+        is_safe_in_alternate_colors(board, card)
+    }
+
+    // whether moves depend on where the tableau columns are, so that swapping two makes another position
+    fn has_ordered_columns(&self) -> bool {
+        false
+    }
+
+    // the highest deal number that means something, None when any will do
+    fn get_max_deal_number(&self) -> Option<u64> {
+        None
+    }
+
+    // cards the stock turns over at a time
+    fn get_draw_count(&self) -> usize {
+        1
+    }
+
+    // times the player may go through the stock, None for no limit
+    fn get_max_passes(&self) -> Option<usize> {
+        None
+    }
+
+    // whether turning the waste back over costs points
+    fn has_redeal_penalty(&self) -> bool {
+        false
+    }
+}
+
+impl Clone for Box<dyn Variant> {
+    fn clone(&self) -> Box<dyn Variant> {
+        self.clone_box()
+    }
+}
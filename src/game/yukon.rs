@@ -0,0 +1,73 @@
+use std::fmt;
+use crate::deck::{Card, Deck};
+use crate::game::board::Board;
+use crate::game::foundation::Foundation;
+use crate::game::render;
+use crate::game::variant::Variant;
+use crate::game::yukon_tableau::YukonTableau;
+use crate::game::{PileKind, Spot, SUITS};
+
+const TABLEAU_COUNT: usize = 7;
+// every column but the first gets this many face-up cards on its face-down ones
+const OPEN_CARDS: usize = 5;
+
+// the whole deck dealt out on seven columns with no stock, any face-up card can be moved
+// with the cards on top of it
+#[derive(Clone)]
+pub struct Yukon {
+    // Russian Solitaire, the same game building in suit
+    russian: bool,
+}
+
+impl Yukon {
+    pub fn new(russian: bool) -> Yukon {
+        Yukon {
+            russian,
+        }
+    }
+}
+
+impl Variant for Yukon {
+    fn get_name(&self) -> &'static str {
+        if self.russian { "russian" } else { "yukon" }
+    }
+
+    fn clone_box(&self) -> Box<dyn Variant> {
+        Box::new(self.clone())
+    }
+
+    fn get_title(&self) -> String {
+        String::from(if self.russian { "Russian Solitaire" } else { "Yukon" })
+    }
+
+    fn deal(&self, deal_number: u64) -> Board {
+        let mut deck = Deck::from_seed(deal_number);
+
+        // the first column is a single card, the n-th one has n - 1 face-down cards under the face-up ones
+        let columns: Vec<Vec<Card>> = (0..TABLEAU_COUNT)
+            .map(|i| {
+                let open = if i == 0 { 1 } else { OPEN_CARDS };
+                (0..i + open).map(|j| deck.deal(j >= i).unwrap()).collect()
+            })
+            .collect();
+
+        let mut board = Board::new();
+        for suit in SUITS.iter() {
+            board.add(Box::new(Foundation::new(*suit, false)));
+        }
+        for column in columns {
+            board.add(Box::new(YukonTableau::new(column, self.russian)));
+        }
+
+        board
+    }
+
+    fn render(&self, board: &Board, hinted: &dyn Fn(Spot) -> bool, f: &mut fmt::Formatter) -> fmt::Result {
+        for (id, suit) in board.ids_of(PileKind::Foundation).iter().zip(SUITS.iter()) {
+            render::write_top(f, board, *id, suit.get_symbol(), hinted)?;
+        }
+        write!(f, "\n\n")?;
+
+        render::write_columns(f, board, &board.ids_of(PileKind::Tableau), hinted)
+    }
+}
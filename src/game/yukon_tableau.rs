@@ -0,0 +1,62 @@
+use crate::game::{MoveError, Pile, PileKind};
+use crate::deck::{Card, Rank};
+
+// builds down like a Klondike column, but any face-up card moves with whatever lies on it
+#[derive(Clone)]
+pub struct YukonTableau {
+    stack: Vec<Card>,
+    // Russian Solitaire builds in suit instead of in alternate colors
+    same_suit: bool,
+}
+
+impl YukonTableau {
+    pub fn new(stack: Vec<Card>, same_suit: bool) -> YukonTableau {
+        YukonTableau {
+            stack,
+            same_suit,
+        }
+    }
+}
+
+impl Pile for YukonTableau {
+    fn get_kind(&self) -> PileKind {
+        PileKind::Tableau
+    }
+
+    fn get_cards(&self) -> &Vec<Card> {
+        &self.stack
+    }
+
+    fn get_cards_mut(&mut self) -> &mut Vec<Card> {
+        &mut self.stack
+    }
+
+    fn clone_box(&self) -> Box<dyn Pile> {
+        Box::new(self.clone())
+    }
+
+    fn check_push(&self, card: &Card) -> Result<(), MoveError> {
+        let last = match self.stack.last() {
+            Some(last) => last,
+            None if card.get_rank() == Rank::King => return Ok(()),
+            None => return Err(MoveError::NotKing { card: card.clone() }),
+        };
+
+        if last.get_numbered_rank() != card.get_numbered_rank() + 1 {
+            return Err(MoveError::WrongRank { card: card.clone(), onto: last.clone() });
+        }
+        if self.same_suit && last.get_suit() != card.get_suit() {
+            return Err(MoveError::DifferentSuit { card: card.clone(), onto: last.clone() });
+        }
+        if !self.same_suit && last.get_suit().is_red() == card.get_suit().is_red() {
+            return Err(MoveError::SameColor { card: card.clone(), onto: last.clone() });
+        }
+
+        Ok(())
+    }
+
+    // the cards on top come along in whatever order they are
+    fn moves_runs(&self) -> bool {
+        true
+    }
+}
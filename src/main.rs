@@ -1,6 +1,10 @@
 mod deck;
 mod game;
 mod menu;
+mod settings;
+mod solver;
+mod stats;
+mod storage;
 
 fn main() {
     menu::start();
@@ -1,5 +1,8 @@
 use std::io::{self, Write};
+use std::time::Duration;
 use crate::game;
+use crate::settings::{self, Settings};
+use crate::stats::Statistics;
 
 fn flush() {
     io::stdout().flush().expect("flush failed!");
@@ -10,9 +13,19 @@ fn clear_screen() {
     flush();
 }
 
-fn print_header() {
+pub fn format_time(time: Duration) -> String {
+    let seconds = time.as_secs();
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+fn print_header(game: &game::Game) {
     clear_screen();
-    println!("        SOLITAIRE\n\n\n\n");
+    println!("        SOLITAIRE\n");
+
+    match game.get_scoring() {
+        game::Scoring::Standard => println!("Score: {}\n\n", game.get_score()),
+        game::Scoring::Vegas => println!("Score: ${}    Bankroll: ${}\n\n", game.get_score(), game.get_bankroll()),
+    }
 }
 
 pub fn read_line(prompt: &str) -> String {
@@ -29,10 +42,48 @@ pub fn read_line(prompt: &str) -> String {
 }
 
 pub fn print_game(game: &game::Game) {
-    print_header();
+    print_header(game);
+    print!("Deal #{}    Moves: {}    Time: {}", game.get_deal_number(), game.get_move_counts().total(), format_time(game.get_elapsed()));
+    if let Some(passes_left) = game.get_passes_left() {
+        print!("    Passes left: {}", passes_left);
+    }
+    println!("\n");
     println!("{}", game);
 }
 
+pub fn print_game_over(game: &game::Game) {
+    print_header(game);
+    println!("{}", game);
+
+    if game.is_won() {
+        println!("        YOU WON!\n");
+    } else {
+        println!("        NO MOVES LEFT\n");
+    }
+
+    let counts = game.get_move_counts();
+    println!("Deal:  #{}", game.get_deal_number());
+    println!("Moves: {}", counts.total());
+    println!("  deals {}, waste turned over {}", counts.deals, counts.recycles);
+    println!("  waste to foundation {}, waste to tableau {}", counts.waste_to_foundation, counts.waste_to_tableau);
+    println!("  tableau to foundation {}, tableau to tableau {}", counts.tableau_to_foundation, counts.tableau_to_tableau);
+    println!("  foundation to tableau {}, undos {}", counts.foundation_to_tableau, counts.undos);
+    println!("Time:  {}", format_time(game.get_elapsed()));
+    if game.get_time_bonus() > 0 {
+        println!("Time bonus: {}", game.get_time_bonus());
+    }
+    match game.get_scoring() {
+        game::Scoring::Standard => println!("Score: {}\n", game.get_score()),
+        game::Scoring::Vegas => println!("Score: ${}\n", game.get_score()),
+    }
+
+    if !game.is_won() {
+        println!("u => undo the last move and keep playing");
+    }
+    println!("r => replay this deal");
+    println!("n => play a new deal");
+    println!("m => return to the main menu\n");
+}
 
 pub fn print_main_menu() {
     clear_screen();
@@ -55,6 +106,65 @@ pub fn print_main_menu() {
     flush();
 }
 
+pub fn print_games(titles: &[&str]) {
+    clear_screen();
+    println!("        GAMES\n");
+
+    for (i, title) in titles.iter().enumerate() {
+        println!("{:>2} => {}", i + 1, title);
+    }
+    println!();
+}
+
+fn or_dash<T>(value: Option<T>, format: impl Fn(T) -> String) -> String {
+    value.map_or_else(|| String::from("-"), format)
+}
+
+pub fn print_statistics(statistics: &Statistics) {
+    clear_screen();
+    println!("        STATISTICS\n");
+
+    if statistics.games.is_empty() {
+        println!("No games played yet.\n");
+    } else {
+        println!("{:<18}{:>7}{:>6}{:>7}{:>8}{:>6}{:>9}{:>7}{:>7}", "", "Played", "Won", "Win %", "Streak", "Best", "Fastest", "Moves", "Score");
+        for (title, stats) in statistics.games.iter() {
+            println!(
+                "{:<18}{:>7}{:>6}{:>7}{:>8}{:>6}{:>9}{:>7}{:>7}",
+                title,
+                stats.played,
+                stats.won,
+                format!("{:.1}", stats.win_percentage()),
+                stats.current_streak,
+                stats.best_streak,
+                or_dash(stats.fastest_win, format_time),
+                or_dash(stats.fewest_moves, |moves| moves.to_string()),
+                or_dash(stats.best_score, |score| score.to_string()),
+            );
+        }
+        println!();
+        println!("Streak is the current winning streak, Best the longest one, Moves and Score the best wins.\n");
+    }
+
+    println!("Vegas bankroll: ${}\n", statistics.bankroll);
+}
+
+pub fn print_settings(settings: &Settings) {
+    clear_screen();
+    println!("        SETTINGS\n");
+
+    let rules = &settings.rules;
+    println!("1 => passes through the stock: {}", rules.pass_limit);
+    println!("2 => points off for turning the waste over: {}", settings::write_switch(rules.redeal_penalty));
+    println!("3 => any card on an empty column: {}", settings::write_switch(rules.any_card_on_empty_column));
+    println!("4 => cards back from the foundations: {}", settings::write_switch(rules.foundation_to_tableau));
+    println!("5 => foundations take any suit: {}", settings::write_switch(rules.any_suit_foundations));
+    println!("6 => move part of a face-up run: {}", settings::write_switch(rules.partial_runs));
+    println!("7 => auto-play: {}", settings::write_switch(settings.auto_play));
+    println!("d => back to the usual rules\n");
+    println!("Rule changes apply to new games.\n");
+}
+
 pub fn print_messages(messages: &mut Vec<String>) {
     messages.reverse();
     while let Some(message) = messages.pop() {
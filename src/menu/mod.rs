@@ -1,94 +1,493 @@
 mod console;
 mod parser;
 
+use std::thread;
+use std::time::Duration;
 use parser::{Command, MoveSpec};
-use crate::game::{Game, DealSize};
+use crate::game::{self, FreeCell, Game, Klondike, KlondikeRules, Spider, Yukon, Pyramid, TriPeaks, Golf, Accordion, Clock, Canfield, FortyThieves, BakersDozen, BeleagueredCastle, Fortress, EightOff, Variant, Move, Scoring, MIN_DRAW_COUNT, MAX_DRAW_COUNT};
+use crate::solver::{self, Verdict};
+use crate::settings::Settings;
+use crate::stats::Statistics;
+use crate::storage;
 
-pub fn start() {
-    let mut deal_size: Option<DealSize> = None;
+// how many positions the solve command looks at before giving up
+const SOLVER_MAX_STATES: usize = 100_000;
+const SOLUTION_MOVES_PER_LINE: usize = 4;
+// pause between the moves autocomplete plays so they can be followed
+const AUTOCOMPLETE_STEP_DELAY: Duration = Duration::from_millis(150);
+
+// the Vegas money is carried over from the games recorded in the statistics
+fn choose_game(settings: &mut Settings, messages: &mut Vec<String>) -> Game {
+    let mut variant: Option<Box<dyn Variant>> = None;
 
-    while deal_size.is_none() {
+    while variant.is_none() {
         console::print_main_menu();
+        console::print_messages(messages);
 
-        let input = console::read_line("Choose deal size (1 or 3):");
+        let has_autosave = storage::has_autosave();
+        let prompt = if has_autosave {
+            format!("Choose Klondike deal size ({} to {}), g for other games, s for statistics, o for settings or c to continue the last game:", MIN_DRAW_COUNT, MAX_DRAW_COUNT)
+        } else {
+            format!("Choose Klondike deal size ({} to {}), g for other games, s for statistics or o for settings:", MIN_DRAW_COUNT, MAX_DRAW_COUNT)
+        };
+        let input = console::read_line(&prompt);
 
-        deal_size = match input.parse() {
-            Ok(num) => {
-                match num {
-                    1 => Some(DealSize::One),
-                    3 => Some(DealSize::Three),
-                    _ => continue,
+        match input.as_str() {
+            "s" | "stats" | "statistics" => show_statistics(messages),
+            "o" | "settings" => change_settings(settings, messages),
+            "c" | "continue" if has_autosave => {
+                match storage::load_autosave() {
+                    Ok(game) => return game,
+                    Err(message) => messages.push(message),
                 }
             },
-            Err(_) => continue,
+            "g" | "games" => variant = choose_other_game(),
+            _ => {
+                variant = parse_deal_size(&input).map(|deal_size| {
+                    let rules = KlondikeRules {
+                        draw_count: deal_size,
+                        ..settings.rules
+                    };
+                    Box::new(Klondike::new(rules)) as Box<dyn Variant>
+                });
+            },
+        }
+    }
+    let variant = variant.unwrap();
+
+    // starting over gives up on the game that was left unfinished
+    if storage::has_autosave() {
+        if let Ok(game) = storage::load_autosave() {
+            if !game.is_won() && game.get_move_counts().total() > 0 {
+                record_game(&game, messages);
+            }
+        }
+        storage::remove_autosave();
+    }
+
+    let max_deal_number = variant.get_max_deal_number();
+    let prompt = match max_deal_number {
+        Some(max) => format!("Choose deal number (1 to {}, empty for a random deal):", max),
+        None => String::from("Choose deal number (empty for a random deal):"),
+    };
+    let mut deal_number: Option<u64> = None;
+
+    while deal_number.is_none() {
+        let input = console::read_line(&prompt);
+        let input = input.trim_start_matches('#');
+
+        if input.is_empty() {
+            deal_number = Some(game::random_deal_number(max_deal_number));
+            continue;
+        }
+
+        deal_number = match input.parse() {
+            Ok(num) if max_deal_number.is_none_or(|max| (1..=max).contains(&num)) => Some(num),
+            _ => continue,
         };
     }
 
-    let mut game = Game::new(deal_size.unwrap());
+    let mut scoring: Option<Scoring> = None;
+
+    while scoring.is_none() {
+        let input = console::read_line("Choose scoring (s for standard or v for Vegas):");
+
+        scoring = match input.as_str() {
+            "s" | "standard" => Some(Scoring::Standard),
+            "v" | "vegas" => Some(Scoring::Vegas),
+            _ => None,
+        };
+    }
+
+    let bankroll = storage::load_statistics().map(|statistics| statistics.bankroll).unwrap_or(0);
+
+    let mut game = Game::new(variant, deal_number.unwrap(), scoring.unwrap());
+    game.set_bankroll(bankroll);
+    game
+}
+
+// the games besides Klondike, as the games menu numbers them
+const OTHER_GAMES: [&str; 15] = [
+    "FreeCell", "Spider", "Yukon", "Russian Solitaire", "Pyramid", "TriPeaks", "Golf", "Accordion", "Clock", "Canfield", "Forty Thieves",
+    "Baker's Dozen", "Beleaguered Castle", "Fortress", "Eight Off",
+];
+
+fn choose_other_game() -> Option<Box<dyn Variant>> {
+    console::print_games(&OTHER_GAMES);
+    let input = console::read_line("Choose a game, anything else to go back:");
+
+    match input.as_str() {
+        "1" => Some(Box::new(FreeCell::new())),
+        "2" => {
+            let mut suit_count: Option<usize> = None;
+            while suit_count.is_none() {
+                let input = console::read_line("Choose the number of suits (1, 2 or 4):");
+                suit_count = input.parse().ok().filter(|count| Spider::SUIT_COUNTS.contains(count));
+            }
+            Some(Box::new(Spider::new(suit_count.unwrap())))
+        },
+        "3" => Some(Box::new(Yukon::new(false))),
+        "4" => Some(Box::new(Yukon::new(true))),
+        "5" => Some(Box::new(Pyramid::new())),
+        "6" => Some(Box::new(TriPeaks::new())),
+        "7" => Some(Box::new(Golf::new())),
+        "8" => Some(Box::new(Accordion::new())),
+        "9" => Some(Box::new(Clock::new())),
+        "10" => Some(Box::new(Canfield::new())),
+        "11" => Some(Box::new(FortyThieves::new())),
+        "12" => Some(Box::new(BakersDozen::new())),
+        "13" => Some(Box::new(BeleagueredCastle::new())),
+        "14" => Some(Box::new(Fortress::new())),
+        "15" => Some(Box::new(EightOff::new())),
+        _ => None,
+    }
+}
+
+fn record_game(game: &Game, messages: &mut Vec<String>) {
+    let result = storage::load_statistics().and_then(|mut statistics| {
+        statistics.record(game);
+        storage::save_statistics(&statistics)
+    });
+
+    if let Err(message) = result {
+        messages.push(message);
+    }
+}
+
+fn show_statistics(messages: &mut Vec<String>) {
+    let statistics = match storage::load_statistics() {
+        Ok(statistics) => statistics,
+        Err(message) => {
+            messages.push(message);
+            return;
+        },
+    };
+
+    console::print_statistics(&statistics);
+    let input = console::read_line("r to reset the statistics, anything else to go back:");
+    if input != "r" && input != "reset" {
+        return;
+    }
+
+    let input = console::read_line("This clears every game played and the Vegas bankroll. Type yes to confirm:");
+    if input != "yes" {
+        return;
+    }
+
+    match storage::save_statistics(&Statistics::default()) {
+        Ok(()) => messages.push(String::from("Statistics reset.")),
+        Err(message) => messages.push(message),
+    }
+}
+
+// house rules apply to the games started after they are changed
+fn change_settings(settings: &mut Settings, messages: &mut Vec<String>) {
+    loop {
+        console::print_settings(settings);
+        console::print_messages(messages);
+        let input = console::read_line("Choose a setting to change, anything else to go back:");
+
+        let rules = &mut settings.rules;
+        match input.as_str() {
+            "1" => rules.pass_limit = rules.pass_limit.next(),
+            "2" => rules.redeal_penalty = !rules.redeal_penalty,
+            "3" => rules.any_card_on_empty_column = !rules.any_card_on_empty_column,
+            "4" => rules.foundation_to_tableau = !rules.foundation_to_tableau,
+            "5" => rules.any_suit_foundations = !rules.any_suit_foundations,
+            "6" => rules.partial_runs = !rules.partial_runs,
+            "7" => settings.auto_play = !settings.auto_play,
+            "d" | "default" => {
+                settings.rules = KlondikeRules::default();
+            },
+            _ => return,
+        }
+
+        if let Err(message) = storage::save_settings(settings) {
+            messages.push(message);
+        }
+    }
+}
+
+fn parse_deal_size(input: &str) -> Option<usize> {
+    match input.parse() {
+        Ok(num) if (MIN_DRAW_COUNT..=MAX_DRAW_COUNT).contains(&num) => Some(num),
+        _ => None,
+    }
+}
+
+// the solver's moves for display, with runs of deals written once
+fn solution_steps(moves: &[Move]) -> Vec<String> {
+    let mut steps = Vec::new();
+    let mut deals = 0;
+
+    for mv in moves {
+        if *mv == Move::Deal {
+            deals += 1;
+            continue;
+        }
+
+        if deals > 0 {
+            steps.push(format!("deal {} time(s)", deals));
+            deals = 0;
+        }
+        steps.push(mv.to_string());
+    }
+
+    steps
+}
+
+// plays the rest of a game whose cards are all face-up, redrawing the board after every move
+fn autocomplete(game: &mut Game, messages: &mut Vec<String>) {
+    if !game.can_autocomplete() {
+        messages.push(String::from("Autocomplete needs every tableau card face-up."));
+        return;
+    }
+
+    let moves = match solver::solve(game, SOLVER_MAX_STATES).verdict {
+        Verdict::Solved(moves) => moves,
+        _ => {
+            messages.push(String::from("This game cannot be finished automatically."));
+            return;
+        },
+    };
+
+    for (i, mv) in moves.iter().enumerate() {
+        if let Err(error) = game.play(*mv) {
+            messages.push(format!("Autocomplete stopped, cannot play {}: {}", mv, error));
+            return;
+        }
+
+        console::print_game(game);
+        println!("Autocomplete {}/{}: {}.", i + 1, moves.len(), mv);
+        thread::sleep(AUTOCOMPLETE_STEP_DELAY);
+    }
+
+    messages.push(format!("Autocompleted in {} moves.", moves.len()));
+}
+
+fn toggle_auto_play(settings: &mut Settings, messages: &mut Vec<String>) {
+    settings.auto_play = !settings.auto_play;
+
+    if settings.auto_play {
+        messages.push(String::from("Auto-play is on, safe cards go to the foundations by themselves."));
+    } else {
+        messages.push(String::from("Auto-play is off."));
+    }
+
+    if let Err(message) = storage::save_settings(settings) {
+        messages.push(message);
+    }
+}
+
+enum GameEnd {
+    Over,
+    Quit,
+}
+
+// asks what to do once a game is won or stuck, None means keep playing the same game
+fn game_over(game: &mut Game, settings: &mut Settings, messages: &mut Vec<String>) -> Option<Game> {
+    messages.clear();
+
+    loop {
+        console::print_game_over(game);
+        let input = console::read_line("Choose an option:");
+
+        if input == "u" && !game.is_won() {
+            if let Some(mv) = game.undo() {
+                messages.push(format!("Undid {}.", mv));
+            }
+            return None;
+        }
+
+        if input != "r" && input != "n" && input != "m" {
+            continue;
+        }
+
+        record_game(game, messages);
+        storage::remove_autosave();
+
+        return match input.as_str() {
+            "r" => Some(game.restart()),
+            "n" => Some(game.new_deal()),
+            _ => Some(choose_game(settings, messages)),
+        };
+    }
+}
+
+pub fn start() {
     let mut messages: Vec<String> = Vec::new();
+    let mut settings = storage::load_settings().unwrap_or_else(|message| {
+        messages.push(message);
+        Settings::default()
+    });
+    let mut game = choose_game(&mut settings, &mut messages);
 
     loop {
-        console::print_game(&game);
-        console::print_messages(&mut messages);
+        match play(&mut game, &mut settings, &mut messages) {
+            GameEnd::Over => {
+                if let Some(next) = game_over(&mut game, &mut settings, &mut messages) {
+                    game = next;
+                }
+            },
+            GameEnd::Quit => game = choose_game(&mut settings, &mut messages),
+        }
+    }
+}
+
+fn play(game: &mut Game, settings: &mut Settings, messages: &mut Vec<String>) -> GameEnd {
+    game.resume_timer();
+    let end = play_turns(game, settings, messages);
+    game.pause_timer();
+
+    if let Err(message) = storage::autosave(game) {
+        messages.push(message);
+    }
+
+    end
+}
+
+fn play_turns(game: &mut Game, settings: &mut Settings, messages: &mut Vec<String>) -> GameEnd {
+    // asking for a hint again shows the next best move
+    let mut hint_index = 0;
+
+    loop {
+        if game.is_won() || game.is_stuck() {
+            return GameEnd::Over;
+        }
+
+        console::print_game(game);
+        console::print_messages(messages);
         let input = console::read_line("command: ");
 
-        match Command::parse(&input) {
+        match Command::parse(&input, game) {
             Ok(command) => {
+                match command {
+                    Command::Hint => (),
+                    _ => hint_index = 0,
+                }
+                // undo and redo are left alone so they can step through the moves auto-play made
+                let auto_play = settings.auto_play && matches!(command, Command::Deal | Command::Move(_));
+
                 match command {
                     Command::Deal => {
-                        game.deal();
-                        messages.push(String::from("Dealt cards."));
+                        match game.deal() {
+                            Ok(()) => messages.push(String::from("Dealt cards.")),
+                            Err(error) => messages.push(error.to_string()),
+                        }
                     },
                     Command::Help => {
                         messages.push(String::from("d or deal => deals cards"));
-                        messages.push(String::from("m or move => moves cards"));
+                        messages.push(String::from("m or move => moves cards, asking where from and where to"));
+                        messages.push(String::from("m <from> <to> [size] => moves on one line, like m 3 5 2, m 7H 8S or m QD 4, without a size the longest run that fits"));
+                        messages.push(String::from("<from><to> => short moves like w5 or 3h, f picks the card's foundation and x a free cell"));
+                        messages.push(String::from("<card> => names a card of the Pyramid or TriPeaks layout, like m 7H 6S, as they have no numbers"));
+                        messages.push(String::from("u or undo => takes back the last move"));
+                        messages.push(String::from("r or redo => plays the last undone move again"));
+                        messages.push(String::from("save <name> => saves the game"));
+                        messages.push(String::from("load <name> => loads a saved game"));
+                        messages.push(String::from("hint => points out a good move, again for the next one"));
+                        messages.push(String::from("solve => searches for a way to win from here"));
+                        messages.push(String::from("auto => turns playing safe cards to the foundations on or off"));
+                        messages.push(String::from("ac or autocomplete => finishes the game once every card is face-up"));
+                        messages.push(String::from("q or quit => returns to the main menu"));
                         messages.push(String::from("h or help => display this help"));
                     },
-                    Command::Move(spec) => {
-                        match spec {
-                            MoveSpec::WasteToFoundation(suit) => {
-                                let success = game.waste_to_foundation(suit);
-                                if success {
-                                    messages.push(String::from(format!("Moved from waste to foundation {}.", suit)));
-                                } else {
-                                    messages.push(String::from(format!("Cannot move from waste to foundation {}.", suit)));
-                                }
-                            },
-                            MoveSpec::WasteToTableau(n) => {
-                                let success = game.waste_to_tableau(n);
-                                if success {
-                                    messages.push(String::from(format!("Moved from waste to tableau {}.", n)));
-                                } else {
-                                    messages.push(String::from(format!("Cannot move move from waste to tableau {}.", n)));
+                    Command::Undo => {
+                        match game.undo() {
+                            Some(mv) => messages.push(format!("Undid {}.", mv)),
+                            None => messages.push(String::from("Nothing to undo.")),
+                        }
+                    },
+                    Command::Redo => {
+                        match game.redo() {
+                            Some(mv) => messages.push(format!("Redid {}.", mv)),
+                            None => messages.push(String::from("Nothing to redo.")),
+                        }
+                    },
+                    Command::Quit => return GameEnd::Quit,
+                    Command::AutoPlay => toggle_auto_play(settings, messages),
+                    Command::Autocomplete => autocomplete(game, messages),
+                    Command::Solve => {
+                        let solution = solver::solve(game, SOLVER_MAX_STATES);
+                        match solution.verdict {
+                            Verdict::Solved(moves) => {
+                                messages.push(format!("Solved in {} moves, {} positions explored:", moves.len(), solution.states));
+                                let steps: Vec<String> = solution_steps(&moves).iter().enumerate().map(|(i, step)| format!("{}. {}", i + 1, step)).collect();
+                                for line in steps.chunks(SOLUTION_MOVES_PER_LINE) {
+                                    messages.push(line.join("; "));
                                 }
                             },
-                            MoveSpec::TableauToFoundation(n, suit) => {
-                                let success = game.tableau_to_foundation(n, suit);
-                                if success {
-                                    messages.push(String::from(format!("Moved from tableau {} to foundation {}.", n, suit)));
-                                } else {
-                                    messages.push(String::from(format!("Cannot move from tableau {} to foundation {}.", n, suit)));
+                            Verdict::Unwinnable => messages.push(format!("This game cannot be won, {} positions explored.", solution.states)),
+                            Verdict::GaveUp => messages.push(format!("No solution found after exploring {} positions.", solution.states)),
+                        }
+                    },
+                    Command::Hint => {
+                        let hints = game.hints();
+                        if hints.is_empty() {
+                            messages.push(String::from("No hints left, try undoing some moves."));
+                        } else {
+                            let hint = hints[hint_index % hints.len()];
+                            game.set_hint(Some(hint));
+                            messages.push(format!("Hint {}/{}: {}.", hint_index % hints.len() + 1, hints.len(), hint));
+                            hint_index += 1;
+                        }
+                    },
+                    Command::Save(name) => {
+                        match storage::save_game(&name, game) {
+                            Ok(()) => messages.push(format!("Saved game {}.", name)),
+                            Err(message) => messages.push(message),
+                        }
+                    },
+                    Command::Load(name) => {
+                        match storage::load_game(&name) {
+                            Ok(loaded) => {
+                                // the clock runs for whichever game is being played
+                                game.pause_timer();
+
+                                // loading over a game gives up on it
+                                if !game.is_won() && game.get_move_counts().total() > 0 {
+                                    record_game(game, messages);
                                 }
+
+                                *game = loaded;
+                                game.resume_timer();
+                                messages.push(format!("Loaded game {}.", name));
                             },
-                            MoveSpec::TableauToTableau(n, m, size) => {
-                                let success = game.tableau_to_tableau(n, m, size);
-                                if success {
-                                    messages.push(String::from(format!("Moved from foundation {} to foundation {}.", n, m)));
-                                } else {
-                                    messages.push(String::from(format!("Cannot move from foundation {} to foundation {}.", n, m)));
-                                }
+                            Err(message) => messages.push(message),
+                        }
+                    },
+                    Command::Move(spec) => {
+                        let result = match spec {
+                            MoveSpec::Transfer(from, to, size) => {
+                                let result = match size {
+                                    Some(size) => game.transfer(from, to, size).map(|()| size),
+                                    None => game.transfer_auto(from, to),
+                                };
+                                result.map(|size| match size {
+                                    1 => format!("Moved from {} to {}.", from, to),
+                                    _ => format!("Moved {} card(s) from {} to {}.", size, from, to),
+                                })
                             },
-                            MoveSpec::FoundationToTableau(suit, n) => {
-                                let success = game.foundation_to_tableau(suit, n);
-                                if success {
-                                    messages.push(String::from(format!("Moved from foundation {} to tableau {}.", suit, n)));
-                                } else {
-                                    messages.push(String::from(format!("Cannot move from foundation {} to tableau {}.", suit, n)));
-                                }
-                            }
+                            MoveSpec::ToAny(from, kind) => game.transfer_to_any(from, kind)
+                                .map(|to| format!("Moved from {} to {}.", from, to)),
+                        };
+
+                        match result {
+                            Ok(message) => messages.push(message),
+                            Err(error) => messages.push(error.to_string()),
                         }
                     }
                 }
+
+                if auto_play {
+                    for mv in game.auto_play() {
+                        messages.push(format!("Auto-played {}.", mv));
+                    }
+                }
+
+                if let Err(message) = storage::autosave(game) {
+                    messages.push(message);
+                }
             },
             Err(message) => messages.push(message),
         }
@@ -1,20 +1,45 @@
-use crate::deck::Suit;
+use crate::deck::{Card, Suit};
+use crate::game::{Game, PileId, PileKind};
 use crate::menu::console;
 
 pub enum Command {
     Deal,
     Help,
     Move(MoveSpec),
+    Undo,
+    Redo,
+    Save(String),
+    Load(String),
+    Quit,
+    Hint,
+    Solve,
+    AutoPlay,
+    Autocomplete,
 }
 
 impl Command {
-    pub fn parse(input: &str) -> Result<Command, String> {
-        match input {
-            "d" | "deal" => Ok(Command::Deal),
-            "h" | "help" => Ok(Command::Help),
-            "m" | "move" => match MoveSpec::read_and_parse() {
-                Ok(spec) => Ok(Command::Move(spec)),
-                Err(message) => Err(message)
+    // a command and its arguments on one line, like `m 3 5 2`, `w5` or `save mygame`
+    pub fn parse(input: &str, game: &Game) -> Result<Command, String> {
+        let tokens: Vec<&str> = input.split_whitespace().collect();
+
+        match tokens.as_slice() {
+            ["d" | "deal"] => Ok(Command::Deal),
+            ["h" | "help"] => Ok(Command::Help),
+            ["u" | "undo"] => Ok(Command::Undo),
+            ["r" | "redo"] => Ok(Command::Redo),
+            ["q" | "quit"] => Ok(Command::Quit),
+            ["hint"] => Ok(Command::Hint),
+            ["solve"] => Ok(Command::Solve),
+            ["auto"] => Ok(Command::AutoPlay),
+            ["ac" | "autocomplete"] => Ok(Command::Autocomplete),
+            ["save", name] => Ok(Command::Save(name.to_string())),
+            ["load", name] => Ok(Command::Load(name.to_string())),
+            [command @ ("save" | "load"), ..] => Err(format!("Name the game to {}, like {} mygame.", command, command)),
+            ["m" | "move"] => MoveSpec::read_and_parse(game).map(Command::Move),
+            ["m" | "move", args @ ..] => MoveSpec::from_tokens(args, game).map(Command::Move),
+            [token] => match MoveSpec::from_compact(token, game) {
+                Some(spec) => spec.map(Command::Move),
+                None => Err(format!("{} is not a valid command.", input)),
             },
             _ => Err(format!("{} is not a valid command.", input)),
         }
@@ -22,96 +47,226 @@ impl Command {
 }
 
 enum Location {
-    Waste,
+    Pile(PileId),
     Foundation(Suit),
-    Tableau(usize)
+    // the foundation of the moved card's suit, or the first free cell
+    Any(PileKind),
 }
 
 impl Location {
     fn parse(input: &str) -> Result<Location, String> {
-        match input {
-            "w" | "waste" => Ok(Location::Waste),
+        let input = input.to_ascii_lowercase();
+        match input.as_str() {
+            "w" | "waste" => Ok(Location::Pile(PileId::new(PileKind::Waste, 1))),
+            "f" | "foundation" => Ok(Location::Any(PileKind::Foundation)),
+            "x" | "cell" => Ok(Location::Any(PileKind::Cell)),
             "c" | "clubs" => Ok(Location::Foundation(Suit::Clubs)),
             "d" | "diamonds" => Ok(Location::Foundation(Suit::Diamonds)),
             "h" | "hearts" => Ok(Location::Foundation(Suit::Hearts)),
             "s" | "spades" => Ok(Location::Foundation(Suit::Spades)),
-            "1" | "t1" => Ok(Location::Tableau(1)),
-            "2" | "t2" => Ok(Location::Tableau(2)),
-            "3" | "t3" => Ok(Location::Tableau(3)),
-            "4" | "t4" => Ok(Location::Tableau(4)),
-            "5" | "t5" => Ok(Location::Tableau(5)),
-            "6" | "t6" => Ok(Location::Tableau(6)),
-            "7" | "t7" => Ok(Location::Tableau(7)),
-            _ => Err(format!("{} is not a valid location.", input)),
+            // free cells go by an x and their number, tableaus by their number with or without a t in front
+            _ => {
+                let (kind, number) = match input.strip_prefix('x') {
+                    Some(number) => (PileKind::Cell, number),
+                    None => (PileKind::Tableau, input.strip_prefix('t').unwrap_or(&input)),
+                };
+                match number.parse::<usize>() {
+                    Ok(number) if number > 0 => Ok(Location::Pile(PileId::new(kind, number))),
+                    _ => Err(format!("{} is not a valid location.", input)),
+                }
+            },
+        }
+    }
+
+    // the pile meant, or the kind of pile to pick from
+    fn resolve(self, game: &Game) -> Result<Result<PileId, PileKind>, String> {
+        match self {
+            Location::Pile(id) if id.kind == PileKind::Tableau && game.has_slots() => {
+                Err(String::from("The cards of the layout have no numbers, name them by card, like m 7H 6S."))
+            },
+            Location::Pile(id) => Ok(Ok(id)),
+            Location::Foundation(suit) => match game.foundation_of_suit(suit) {
+                Some(id) => Ok(Ok(id)),
+                None => Err(format!("No foundation is left for {}.", suit)),
+            },
+            Location::Any(kind) => Ok(Err(kind)),
         }
     }
 }
 
 pub enum MoveSpec {
-    WasteToFoundation(Suit),
-    WasteToTableau(usize),
-    TableauToFoundation(usize, Suit),
-    TableauToTableau(usize, usize, usize),
-    FoundationToTableau(Suit, usize),
+    // no size lets the game pick the longest run that fits
+    Transfer(PileId, PileId, Option<usize>),
+    ToAny(PileId, PileKind),
 }
 
 impl MoveSpec {
-    fn parse(from: Location, to: Location) -> Result<MoveSpec, String> {
-        match from {
-            Location::Waste => {
-                match to {
-                    Location::Waste => Err(String::from("Cannot move to waste.")),
-                    Location::Foundation(suit) => Ok(MoveSpec::WasteToFoundation(suit)),
-                    Location::Tableau(n) => Ok(MoveSpec::WasteToTableau(n)),
-                }
-            },
-            Location::Foundation(suit) => {
-                match to {
-                    Location::Waste => Err(String::from("Cannot move to waste.")),
-                    Location::Foundation(_) => Err(String::from("Cannot move from foundation to foundation.")),
-                    Location::Tableau(n) => Ok(MoveSpec::FoundationToTableau(suit, n)),
-                }
-            },
-            Location::Tableau(n) => {
-                match to {
-                    Location::Waste => Err(String::from("Cannot move to waste.")),
-                    Location::Foundation(suit) => Ok(MoveSpec::TableauToFoundation(n, suit)),
-                    Location::Tableau(m) => Ok(MoveSpec::TableauToTableau(n, m, 0)),
-                }
-            },
+    // the card named as the source of a move brings its run along
+    fn from_operands(from: Operand, to: Operand, size: Option<usize>, game: &Game) -> Result<MoveSpec, String> {
+        let (from, run) = from.source(game)?;
+        let from = match from.resolve(game)? {
+            Ok(from) => from,
+            Err(PileKind::Cell) => return Err(String::from("Name the free cell to move from, like x1.")),
+            Err(_) => return Err(String::from("Name the foundation to move from (c, d, h or s).")),
+        };
+
+        match to.destination(game)?.resolve(game)? {
+            Ok(to) => Ok(MoveSpec::Transfer(from, to, size.or(run))),
+            Err(_) if size.or(run).is_some_and(|size| size != 1) => Err(String::from("Only the top card of a tableau can go there.")),
+            Err(kind) => Ok(MoveSpec::ToAny(from, kind)),
         }
     }
 
-    fn read_and_parse() -> Result<MoveSpec, String> {
-        let input = console::read_line("from: ");
-
-        match Location::parse(&input) {
-            Ok(from) => {
-                let input = console::read_line("to: ");
-
-                match Location::parse(&input) {
-                    Ok(to) => {
-                        match Self::parse(from, to) {
-                            Ok(spec) => {
-                                match spec {
-                                    MoveSpec::TableauToTableau(n, m, _) => {
-                                        let input = console::read_line("size: ");
-
-                                        match input.parse() {
-                                            Ok(l) => Ok(MoveSpec::TableauToTableau(n, m, l)),
-                                            Err(_) => Err(String::from("Invalid number of cards.")),
-                                        }
-                                    }
-                                    _ => Ok(spec)
-                                }
-                            },
-                            Err(message) => Err(message),
-                        }
-                    },
-                    Err(message) => Err(message),
-                }
+    // `m <from> <to> [size]` where from and to are locations or cards
+    fn from_tokens(args: &[&str], game: &Game) -> Result<MoveSpec, String> {
+        match args {
+            [from, to] => Self::from_operands(Operand::parse(from)?, Operand::parse(to)?, None, game),
+            [from, to, size] => {
+                let size = size.parse().map_err(|_| String::from("Invalid number of cards."))?;
+                Self::from_operands(Operand::parse(from)?, Operand::parse(to)?, Some(size), game)
             },
-            Err(message) => Err(message),
+            _ => Err(String::from("Write a move as m <from> <to> [size], like m 3 5 2 or m 7H 8S.")),
+        }
+    }
+
+    // two location letters run together, like w5 or 3h
+    fn from_compact(token: &str, game: &Game) -> Option<Result<MoveSpec, String>> {
+        let mut chars = token.chars();
+        let (from, to) = match (chars.next(), chars.next(), chars.next()) {
+            (Some(from), Some(to), None) => (from, to),
+            _ => return None,
+        };
+
+        let from = Location::parse(&from.to_string()).ok()?;
+        let to = Location::parse(&to.to_string()).ok()?;
+        Some(Self::from_operands(Operand::Location(from), Operand::Location(to), None, game))
+    }
+
+    fn read_and_parse(game: &Game) -> Result<MoveSpec, String> {
+        let from = Operand::parse(&console::read_line("from: "))?;
+        let to = Operand::parse(&console::read_line("to: "))?;
+
+        match Self::from_operands(from, to, None, game)? {
+            MoveSpec::Transfer(from, to, None) if from.kind == PileKind::Tableau => Ok(MoveSpec::Transfer(from, to, read_size()?)),
+            spec => Ok(spec),
         }
     }
-}
\ No newline at end of file
+}
+
+// an empty answer leaves the size to the game
+fn read_size() -> Result<Option<usize>, String> {
+    let input = console::read_line("size (empty for the longest run that fits): ");
+    if input.is_empty() {
+        return Ok(None);
+    }
+
+    input.parse().map(Some).map_err(|_| String::from("Invalid number of cards."))
+}
+
+// a location or a face-up card named in a move
+enum Operand {
+    Location(Location),
+    Card(Card),
+}
+
+impl Operand {
+    fn parse(input: &str) -> Result<Operand, String> {
+        if let Ok(location) = Location::parse(input) {
+            return Ok(Operand::Location(location));
+        }
+
+        match Card::from_code(input) {
+            Some(card) => Ok(Operand::Card(card)),
+            None => Err(format!("{} is not a valid location or card.", input)),
+        }
+    }
+
+    // the pile to take from, and how many cards when a card was named
+    fn source(self, game: &Game) -> Result<(Location, Option<usize>), String> {
+        let card = match self {
+            Operand::Location(location) => return Ok((location, None)),
+            Operand::Card(card) => card,
+        };
+
+        match game.find_card(&card.get_rank(), card.get_suit()) {
+            Some(spot) => Ok((Location::Pile(spot.pile), Some(game.get_pile_len(spot.pile) - spot.index))),
+            None => Err(format!("{} is not face-up on the board.", card.get_code())),
+        }
+    }
+
+    // the pile to put on, a card has to be on top of its pile
+    fn destination(self, game: &Game) -> Result<Location, String> {
+        let card = match self {
+            Operand::Location(location) => return Ok(location),
+            Operand::Card(card) => card,
+        };
+
+        match game.find_card(&card.get_rank(), card.get_suit()) {
+            Some(spot) if game.get_pile_len(spot.pile) == spot.index + 1 => Ok(Location::Pile(spot.pile)),
+            _ => Err(format!("{} is not on top of a pile to move onto.", card.get_code())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::tests::nearly_done;
+
+    // what a line parses to, written out so results can be compared
+    fn parsed(input: &str, game: &Game) -> String {
+        match Command::parse(input, game) {
+            Ok(Command::Move(MoveSpec::Transfer(from, to, Some(size)))) => format!("{} to {}, {} card(s)", from, to, size),
+            Ok(Command::Move(MoveSpec::Transfer(from, to, None))) => format!("{} to {}", from, to),
+            Ok(Command::Move(MoveSpec::ToAny(from, kind))) => format!("{} to any {}", from, kind.get_name()),
+            Ok(Command::Save(name)) => format!("save {}", name),
+            Ok(_) => String::from("another command"),
+            Err(message) => message,
+        }
+    }
+
+    #[test]
+    fn parses_moves_on_one_line() {
+        let game = nearly_done();
+        let cases = [
+            ("m w 2", "waste to tableau 2"),
+            ("move w 2", "waste to tableau 2"),
+            ("m 2 1 3", "tableau 2 to tableau 1, 3 card(s)"),
+            ("m c 1", "foundation 1 to tableau 1"),
+            ("m 2 f", "tableau 2 to any foundation"),
+            // a card brings the cards on it along
+            ("m 9H 10S", "waste to tableau 2, 1 card(s)"),
+            ("m QD 4", "foundation 2 to tableau 4, 1 card(s)"),
+            ("m KD t3", "tableau 1 to tableau 3, 1 card(s)"),
+            ("3h", "tableau 3 to foundation 3"),
+            ("w5", "waste to tableau 5"),
+            ("1x", "tableau 1 to any cell"),
+            ("save mygame", "save mygame"),
+            ("hint", "another command"),
+        ];
+
+        for (input, expected) in cases.iter() {
+            assert_eq!(parsed(input, &game), *expected, "parsing {}", input);
+        }
+    }
+
+    #[test]
+    fn explains_what_is_wrong_with_a_line() {
+        let game = nearly_done();
+        let cases = [
+            ("m 2", "Write a move as m <from> <to> [size], like m 3 5 2 or m 7H 8S."),
+            ("m 2 1 x", "Invalid number of cards."),
+            ("m ZZ 1", "ZZ is not a valid location or card."),
+            ("m 5H 1", "5H is not face-up on the board."),
+            ("m 1 10H", "10H is not on top of a pile to move onto."),
+            ("m x 1", "Name the free cell to move from, like x1."),
+            ("m 2 f 2", "Only the top card of a tableau can go there."),
+            ("9z", "9z is not a valid command."),
+            ("save", "Name the game to save, like save mygame."),
+        ];
+
+        for (input, expected) in cases.iter() {
+            assert_eq!(parsed(input, &game), *expected, "parsing {}", input);
+        }
+    }
+}
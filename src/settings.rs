@@ -0,0 +1,82 @@
+use crate::game::{KlondikeRules, PassLimit};
+
+const SETTINGS_HEADER: &str = "solitaire-rs settings";
+const SETTINGS_FORMAT_VERSION: u32 = 1;
+
+// the player's preferences, kept between games
+#[derive(Default)]
+pub struct Settings {
+    // play cards that are no longer needed on the tableau to the foundations
+    pub auto_play: bool,
+    // the house rules new games are dealt with, the draw count is chosen for each game
+    pub rules: KlondikeRules,
+}
+
+fn parse_switch(input: Option<&str>) -> Result<bool, String> {
+    match input {
+        Some("on") => Ok(true),
+        Some("off") => Ok(false),
+        _ => Err(String::from("The settings file is not valid.")),
+    }
+}
+
+// how a setting that is on or off is written, in the settings file and on screen
+pub fn write_switch(on: bool) -> &'static str {
+    if on {
+        "on"
+    } else {
+        "off"
+    }
+}
+
+impl Settings {
+    pub fn to_file(&self) -> String {
+        let mut out = format!("{} {}\n", SETTINGS_HEADER, SETTINGS_FORMAT_VERSION);
+        out.push_str(&format!("auto-play {}\n", write_switch(self.auto_play)));
+        out.push_str(&format!("passes {}\n", self.rules.pass_limit));
+        out.push_str(&format!("redeal-penalty {}\n", write_switch(self.rules.redeal_penalty)));
+        out.push_str(&format!("any-card-on-empty {}\n", write_switch(self.rules.any_card_on_empty_column)));
+        out.push_str(&format!("foundation-to-tableau {}\n", write_switch(self.rules.foundation_to_tableau)));
+        out.push_str(&format!("any-suit-foundations {}\n", write_switch(self.rules.any_suit_foundations)));
+        out.push_str(&format!("partial-runs {}\n", write_switch(self.rules.partial_runs)));
+
+        out
+    }
+
+    pub fn from_file(input: &str) -> Result<Settings, String> {
+        let mut lines = input.lines();
+
+        let header = lines.next().unwrap_or("");
+        let version: u32 = match header.strip_prefix(SETTINGS_HEADER) {
+            Some(version) => version.trim().parse().map_err(|_| String::from("The settings file has no version."))?,
+            None => return Err(String::from("This is not a settings file.")),
+        };
+        if version > SETTINGS_FORMAT_VERSION {
+            return Err(format!("The settings file version {} is newer than this game supports.", version));
+        }
+
+        let mut settings = Settings::default();
+
+        for line in lines {
+            let mut tokens = line.split_whitespace();
+
+            match tokens.next() {
+                Some("auto-play") => settings.auto_play = parse_switch(tokens.next())?,
+                Some("passes") => {
+                    settings.rules.pass_limit = tokens.next()
+                        .and_then(PassLimit::from_name)
+                        .ok_or_else(|| String::from("The settings file is not valid."))?;
+                },
+                Some("redeal-penalty") => settings.rules.redeal_penalty = parse_switch(tokens.next())?,
+                Some("any-card-on-empty") => settings.rules.any_card_on_empty_column = parse_switch(tokens.next())?,
+                Some("foundation-to-tableau") => settings.rules.foundation_to_tableau = parse_switch(tokens.next())?,
+                Some("any-suit-foundations") => settings.rules.any_suit_foundations = parse_switch(tokens.next())?,
+                Some("partial-runs") => settings.rules.partial_runs = parse_switch(tokens.next())?,
+                Some(key) => return Err(format!("Unknown entry {} in settings file.", key)),
+                None => continue,
+            }
+        }
+
+        Ok(settings)
+    }
+}
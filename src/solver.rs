@@ -0,0 +1,253 @@
+use std::collections::HashSet;
+use crate::game::{Game, Move, PileKind};
+
+pub enum Verdict {
+    // the moves that win the game from the position the solver started at
+    Solved(Vec<Move>),
+    // every reachable position was explored and none of them is won
+    Unwinnable,
+    // the search hit its limit before finding an answer
+    GaveUp,
+}
+
+pub struct Solution {
+    pub verdict: Verdict,
+    pub states: usize,
+}
+
+// Dealing onto a waste never changes the tableau, so the only reason to deal is
+// to reach a waste card. Each step is therefore a number of deals followed by one move,
+// which keeps every card the stock can turn up a single step away.
+#[derive(Clone, Copy)]
+struct Step {
+    deals: usize,
+    mv: Move,
+}
+
+// the steps still to try from a position on the search path
+struct Frame {
+    steps: Vec<Step>,
+    next: usize,
+}
+
+fn is_waste_move(mv: &Move) -> bool {
+    matches!(mv, Move::Transfer(from, _, _) if from.kind == PileKind::Waste)
+}
+
+// the steps to try from a position, the most promising first
+fn candidate_steps(game: &mut Game) -> Vec<Step> {
+    // with a draw of three, taking a card off the waste changes which cards
+    // the later deals turn up, so only the tableau is played automatically
+    let draws_one = game.get_deal_size() == 1;
+    let forced = game.safe_foundation_moves()
+        .into_iter()
+        .find(|mv| draws_one || !is_waste_move(mv));
+
+    if let Some(mv) = forced {
+        return vec![Step { deals: 0, mv }];
+    }
+
+    let mut rated: Vec<(u32, Step)> = Vec::new();
+    for mv in game.legal_moves() {
+        if mv != Move::Deal || game.deal_is_a_move() {
+            rated.push((game.rate_move(&mv).unwrap_or(0), Step { deals: 0, mv }));
+        }
+    }
+
+    // a deal that puts cards into play is a move like any other
+    if game.deal_is_a_move() {
+        rated.sort_by_key(|(rating, _)| std::cmp::Reverse(*rating));
+        return rated.into_iter().map(|(_, step)| step).collect();
+    }
+
+    // after the waste is turned back over for the second time every
+    // card the stock can show has been seen
+    let start = game.position_key();
+    let mut deals = 0;
+    let mut recycles = 0;
+    while recycles < 2 && game.deal().is_ok() {
+        deals += 1;
+        if game.get_waste_len() == 0 {
+            recycles += 1;
+            continue;
+        }
+        if game.position_key() == start {
+            break;
+        }
+
+        for mv in game.waste_moves() {
+            rated.push((game.rate_move(&mv).unwrap_or(0), Step { deals, mv }));
+        }
+    }
+    for _ in 0..deals {
+        game.undo();
+    }
+
+    rated.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.deals.cmp(&b.1.deals)));
+    rated.into_iter().map(|(_, step)| step).collect()
+}
+
+fn play_step(game: &mut Game, step: Step) -> bool {
+    for i in 0..step.deals {
+        if game.deal().is_err() {
+            for _ in 0..i {
+                game.undo();
+            }
+            return false;
+        }
+    }
+
+    if game.play(step.mv).is_err() {
+        for _ in 0..step.deals {
+            game.undo();
+        }
+        return false;
+    }
+
+    true
+}
+
+fn undo_step(game: &mut Game, step: Step) {
+    for _ in 0..=step.deals {
+        game.undo();
+    }
+}
+
+// searches every position reachable from the game, face-down cards included,
+// for a way to win, looking at no more than `max_states` positions
+pub fn solve(game: &Game, max_states: usize) -> Solution {
+    let mut game = game.clone();
+    let mut seen: HashSet<Vec<u8>> = HashSet::new();
+    let mut path: Vec<Step> = Vec::new();
+    let mut stack: Vec<Frame> = Vec::new();
+
+    if game.is_won() {
+        return Solution { verdict: Verdict::Solved(Vec::new()), states: 1 };
+    }
+
+    seen.insert(game.position_key());
+    stack.push(Frame { steps: candidate_steps(&mut game), next: 0 });
+
+    while let Some(frame) = stack.last_mut() {
+        if frame.next >= frame.steps.len() {
+            stack.pop();
+            if let Some(step) = path.pop() {
+                undo_step(&mut game, step);
+            }
+            continue;
+        }
+
+        let step = frame.steps[frame.next];
+        frame.next += 1;
+
+        if !play_step(&mut game, step) {
+            continue;
+        }
+
+        if game.is_won() {
+            path.push(step);
+            let moves = path.iter()
+                .flat_map(|step| vec![Move::Deal; step.deals].into_iter().chain(Some(step.mv)))
+                .collect();
+            return Solution { verdict: Verdict::Solved(moves), states: seen.len() };
+        }
+
+        if !seen.insert(game.position_key()) {
+            undo_step(&mut game, step);
+            continue;
+        }
+
+        if seen.len() >= max_states {
+            return Solution { verdict: Verdict::GaveUp, states: seen.len() };
+        }
+
+        path.push(step);
+        stack.push(Frame { steps: candidate_steps(&mut game), next: 0 });
+    }
+
+    Solution { verdict: Verdict::Unwinnable, states: seen.len() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{self, FreeCell, Klondike, KlondikeRules, PassLimit, PileId, Scoring};
+
+    fn klondike(draw_count: usize, pass_limit: PassLimit, deal_number: u64) -> Game {
+        let rules = KlondikeRules { draw_count, pass_limit, ..KlondikeRules::default() };
+        Game::new(Box::new(Klondike::new(rules)), deal_number, Scoring::Standard)
+    }
+
+    // won but for the four Kings, which wait at the bottom of the first four columns
+    fn kings_left() -> Game {
+        let mut text = Game::new(Box::new(FreeCell::new()), 1, Scoring::Standard).to_save();
+        text.truncate(text.find("cell 1").unwrap());
+
+        let ranks = ["A", "2", "3", "4", "5", "6", "7", "8", "9", "10", "J", "Q"];
+        for (i, suit) in ["C", "D", "H", "S"].iter().enumerate() {
+            let cards: Vec<String> = ranks.iter().map(|rank| format!("{}{}", rank, suit)).collect();
+            text.push_str(&format!("foundation {} {}\n", i + 1, cards.join(" ")));
+            text.push_str(&format!("tableau {} K{}\n", i + 1, suit));
+        }
+
+        Game::from_save(&text).unwrap()
+    }
+
+    #[test]
+    fn solves_a_trivial_position() {
+        let game = kings_left();
+
+        match solve(&game, 1000).verdict {
+            Verdict::Solved(moves) => {
+                assert_eq!(moves.len(), 4);
+                let mut replay = game.clone();
+                for mv in moves {
+                    assert!(replay.play(mv).is_ok());
+                }
+                assert!(replay.is_won());
+            },
+            _ => panic!("four Kings to the foundations should be solved"),
+        }
+    }
+
+    #[test]
+    fn a_position_with_no_way_forward_is_unwinnable() {
+        let game = game::tests::waiting_on_the_waste(PassLimit::Single, 0);
+
+        assert!(matches!(solve(&game, 1000).verdict, Verdict::Unwinnable));
+        // with a pass left the 10 of diamonds comes back, but the Jacks stay buried
+        assert!(matches!(solve(&game::tests::waiting_on_the_waste(PassLimit::Three, 0), 1000).verdict, Verdict::Unwinnable));
+    }
+
+    #[test]
+    fn position_key_counts_passes_when_they_are_limited() {
+        for (pass_limit, same) in [(PassLimit::Unlimited, true), (PassLimit::Three, false)] {
+            let mut game = klondike(1, pass_limit, 1);
+            let start = game.position_key();
+
+            // one pass through the stock and back to the same cards
+            while game.get_pile_len(PileId::new(PileKind::Stock, 1)) > 0 {
+                assert!(game.deal().is_ok());
+            }
+            assert!(game.deal().is_ok());
+
+            assert_eq!(game.position_key() == start, same);
+        }
+    }
+
+    #[test]
+    fn solves_a_deal_with_limited_passes() {
+        let game = klondike(3, PassLimit::Three, 15);
+
+        match solve(&game, 20000).verdict {
+            Verdict::Solved(moves) => {
+                let mut replay = game.clone();
+                for mv in moves {
+                    assert!(replay.play(mv).is_ok());
+                }
+                assert!(replay.is_won());
+            },
+            _ => panic!("deal 15 should be solved"),
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+use crate::game::{Game, Scoring};
+
+const STATS_HEADER: &str = "solitaire-rs statistics";
+const STATS_FORMAT_VERSION: u32 = 1;
+
+// results of the games played of one kind
+#[derive(Clone, Default)]
+pub struct GameStats {
+    pub played: u32,
+    pub won: u32,
+    pub current_streak: u32,
+    pub best_streak: u32,
+    pub fastest_win: Option<Duration>,
+    pub fewest_moves: Option<usize>,
+    // standard scoring only, Vegas money is kept in the bankroll
+    pub best_score: Option<i32>,
+}
+
+impl GameStats {
+    pub fn win_percentage(&self) -> f64 {
+        if self.played == 0 {
+            return 0.0;
+        }
+
+        100.0 * f64::from(self.won) / f64::from(self.played)
+    }
+
+    fn record(&mut self, game: &Game) {
+        self.played += 1;
+
+        if !game.is_won() {
+            self.current_streak = 0;
+            return;
+        }
+
+        self.won += 1;
+        self.current_streak += 1;
+        self.best_streak = self.best_streak.max(self.current_streak);
+
+        let elapsed = game.get_elapsed();
+        self.fastest_win = Some(self.fastest_win.map_or(elapsed, |fastest| fastest.min(elapsed)));
+
+        let moves = game.get_move_counts().total();
+        self.fewest_moves = Some(self.fewest_moves.map_or(moves, |fewest| fewest.min(moves)));
+
+        if game.get_scoring() == Scoring::Standard {
+            let score = game.get_score();
+            self.best_score = Some(self.best_score.map_or(score, |best| best.max(score)));
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Statistics {
+    // keyed by the title of the game, which tells the games with different deal sizes apart
+    pub games: BTreeMap<String, GameStats>,
+    // Vegas money carried from game to game
+    pub bankroll: i32,
+}
+
+fn parse_number<T: std::str::FromStr>(input: Option<&str>) -> Result<T, String> {
+    input.and_then(|number| number.parse().ok()).ok_or_else(|| String::from("The statistics file is not valid."))
+}
+
+fn write_optional<T: ToString>(value: Option<T>) -> String {
+    value.map_or_else(|| String::from("-"), |value| value.to_string())
+}
+
+fn parse_optional<T: std::str::FromStr>(input: Option<&str>) -> Result<Option<T>, String> {
+    match input {
+        Some("-") => Ok(None),
+        input => parse_number(input).map(Some),
+    }
+}
+
+fn parse_game_stats<'a>(tokens: &mut impl Iterator<Item = &'a str>) -> Result<GameStats, String> {
+    Ok(GameStats {
+        played: parse_number(tokens.next())?,
+        won: parse_number(tokens.next())?,
+        current_streak: parse_number(tokens.next())?,
+        best_streak: parse_number(tokens.next())?,
+        fastest_win: parse_optional(tokens.next())?.map(Duration::from_millis),
+        fewest_moves: parse_optional(tokens.next())?,
+        best_score: parse_optional(tokens.next())?,
+    })
+}
+
+impl Statistics {
+    // counts a game that was won, got stuck or was given up on
+    pub fn record(&mut self, game: &Game) {
+        self.games.entry(game.get_title()).or_default().record(game);
+
+        if game.get_scoring() == Scoring::Vegas {
+            self.bankroll = game.get_bankroll();
+        }
+    }
+
+    pub fn to_file(&self) -> String {
+        let mut out = format!("{} {}\n", STATS_HEADER, STATS_FORMAT_VERSION);
+        out.push_str(&format!("bankroll {}\n", self.bankroll));
+
+        // the title goes last, it can have spaces in it
+        for (title, stats) in self.games.iter() {
+            out.push_str(&format!(
+                "game {} {} {} {} {} {} {} {}\n",
+                stats.played,
+                stats.won,
+                stats.current_streak,
+                stats.best_streak,
+                write_optional(stats.fastest_win.map(|time| time.as_millis())),
+                write_optional(stats.fewest_moves),
+                write_optional(stats.best_score),
+                title,
+            ));
+        }
+
+        out
+    }
+
+    pub fn from_file(input: &str) -> Result<Statistics, String> {
+        let mut lines = input.lines();
+
+        let header = lines.next().unwrap_or("");
+        let version: u32 = match header.strip_prefix(STATS_HEADER) {
+            Some(version) => parse_number(Some(version.trim()))?,
+            None => return Err(String::from("This is not a statistics file.")),
+        };
+        if version > STATS_FORMAT_VERSION {
+            return Err(format!("The statistics file version {} is newer than this game supports.", version));
+        }
+
+        let mut statistics = Statistics::default();
+
+        for line in lines {
+            let mut tokens = line.split_whitespace();
+
+            match tokens.next() {
+                Some("bankroll") => statistics.bankroll = parse_number(tokens.next())?,
+                Some("game") => {
+                    let stats = parse_game_stats(&mut tokens)?;
+                    let title = tokens.collect::<Vec<&str>>().join(" ");
+                    if title.is_empty() {
+                        return Err(String::from("The statistics file is not valid."));
+                    }
+                    statistics.games.insert(title, stats);
+                },
+                Some(key) => return Err(format!("Unknown entry {} in statistics file.", key)),
+                None => continue,
+            }
+        }
+
+        Ok(statistics)
+    }
+}
@@ -0,0 +1,111 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::game::Game;
+use crate::settings::Settings;
+use crate::stats::Statistics;
+
+const APP_DIR: &str = "solitaire-rs";
+const SAVES_DIR: &str = "saves";
+const SAVE_EXTENSION: &str = "save";
+const AUTOSAVE_NAME: &str = "autosave";
+const STATS_FILE: &str = "statistics";
+const SETTINGS_FILE: &str = "settings";
+
+// $XDG_DATA_HOME/solitaire-rs, falling back to ~/.local/share/solitaire-rs
+pub fn data_dir() -> Option<PathBuf> {
+    let base = match env::var_os("XDG_DATA_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(env::var_os("HOME")?).join(".local").join("share"),
+    };
+
+    Some(base.join(APP_DIR))
+}
+
+fn save_path(name: &str) -> Result<PathBuf, String> {
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        return Err(format!("{} is not a valid save name.", name));
+    }
+
+    let dir = data_dir().ok_or_else(|| String::from("Cannot find a directory to save games in."))?;
+    Ok(dir.join(SAVES_DIR).join(name).with_extension(SAVE_EXTENSION))
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("Cannot create {}: {}", dir.display(), e))?;
+    }
+
+    // write to a temporary file first so a crash never leaves half a file behind
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, contents).map_err(|e| format!("Cannot write {}: {}", tmp_path.display(), e))?;
+    fs::rename(&tmp_path, path).map_err(|e| format!("Cannot write {}: {}", path.display(), e))
+}
+
+pub fn save_game(name: &str, game: &Game) -> Result<(), String> {
+    write_file(&save_path(name)?, &game.to_save())
+}
+
+pub fn load_game(name: &str) -> Result<Game, String> {
+    let path = save_path(name)?;
+    let input = fs::read_to_string(&path).map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+
+    Game::from_save(&input)
+}
+
+pub fn autosave(game: &Game) -> Result<(), String> {
+    save_game(AUTOSAVE_NAME, game)
+}
+
+pub fn load_autosave() -> Result<Game, String> {
+    load_game(AUTOSAVE_NAME)
+}
+
+pub fn has_autosave() -> bool {
+    match save_path(AUTOSAVE_NAME) {
+        Ok(path) => path.exists(),
+        Err(_) => false,
+    }
+}
+
+// a finished game should not be offered to continue
+pub fn remove_autosave() {
+    if let Ok(path) = save_path(AUTOSAVE_NAME) {
+        let _ = fs::remove_file(path);
+    }
+}
+
+fn data_file_path(name: &str) -> Result<PathBuf, String> {
+    let dir = data_dir().ok_or_else(|| format!("Cannot find a directory to keep {} in.", name))?;
+    Ok(dir.join(name))
+}
+
+// no file yet means no games were recorded
+pub fn load_statistics() -> Result<Statistics, String> {
+    let path = data_file_path(STATS_FILE)?;
+    if !path.exists() {
+        return Ok(Statistics::default());
+    }
+
+    let input = fs::read_to_string(&path).map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+    Statistics::from_file(&input)
+}
+
+pub fn save_statistics(statistics: &Statistics) -> Result<(), String> {
+    write_file(&data_file_path(STATS_FILE)?, &statistics.to_file())
+}
+
+// no file yet means the defaults
+pub fn load_settings() -> Result<Settings, String> {
+    let path = data_file_path(SETTINGS_FILE)?;
+    if !path.exists() {
+        return Ok(Settings::default());
+    }
+
+    let input = fs::read_to_string(&path).map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+    Settings::from_file(&input)
+}
+
+pub fn save_settings(settings: &Settings) -> Result<(), String> {
+    write_file(&data_file_path(SETTINGS_FILE)?, &settings.to_file())
+}